@@ -0,0 +1,12 @@
+# Spanish speakers merge b and v, and find th, j and final clusters hard.
+
+name = Spanish
+
+B 1.5
+V 1.5
+Th 1.0
+Dj 1.0
+Ptk 0.5
+
+b -> B "b, merged with v by Spanish speakers"
+v -> V "v, merged with b by Spanish speakers"
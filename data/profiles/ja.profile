@@ -0,0 +1,15 @@
+# Japanese speakers merge r and l, and find v, th and f hard.
+
+name = Japanese
+
+R 1.5
+L 1.5
+V 1.0
+Th 1.0
+W 0.5
+
+rr -> R "r, merged with l by Japanese speakers"
+r -> R "r, merged with l by Japanese speakers"
+ll -> L "l, merged with r by Japanese speakers"
+l -> L "l, merged with r by Japanese speakers"
+v -> V "v, pronounced as b by Japanese speakers"
@@ -0,0 +1,14 @@
+# Russian speakers: the original sounds of the highlighter.
+#
+# Each line is a sound kind with its weight or a rule (see `data/en.rules`),
+# the rules of the profile are checked before the rules of the language.
+
+name = Russian
+
+Ptk 1.0
+Th 1.5
+W 1.0
+V 0.5
+Ng 1.0
+Ch 0.5
+Dj 1.0
@@ -0,0 +1,9 @@
+# Chinese speakers drop or add a vowel after final consonants, and find th and v hard.
+
+name = Chinese
+
+final = 1.5
+
+Ptk 1.0
+Th 1.0
+V 1.0
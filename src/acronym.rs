@@ -0,0 +1,117 @@
+use crate::scanner::Scanner;
+use crate::sound::{Sound, SoundKind};
+use crate::tokenizer::Token;
+
+/// Acronyms that are spoken as words, not letter by letter
+const WORD_ACRONYMS: [&str; 24] = [
+    "NATO", "NASA", "ASAP", "UNESCO", "UNICEF", "NAFTA", "OPEC", "SCUBA", "LASER", "RADAR",
+    "SONAR", "AIDS", "FIFA", "UEFA", "GIF", "PIN", "SIM", "YOLO", "COVID", "POTUS", "FEMA",
+    "NASDAQ", "OSHA", "IKEA",
+];
+
+/// Letter names with sounds of the letter name pronunciation: "tee" has an aspirated T,
+/// "aitch" has Ch, "wy" starts with W and so on. Other letter names have no such sounds.
+const LETTER_NAMES: [(&str, SoundKind); 9] = [
+    ("G", SoundKind::Dj),
+    ("H", SoundKind::Ch),
+    ("J", SoundKind::Dj),
+    ("K", SoundKind::Ptk),
+    ("P", SoundKind::Ptk),
+    ("Q", SoundKind::Ptk),
+    ("T", SoundKind::Ptk),
+    ("V", SoundKind::V),
+    ("Y", SoundKind::W),
+];
+
+/// Plural suffix of the initialism, e.g. "URLs"
+const PLURAL_SUFFIX: &str = "s";
+
+/// Minimal count of the words in the text to consider it written in capitals
+const MIN_CAPITALS_TEXT_WORDS: usize = 3;
+
+/// Returns true if the word is an initialism that is spoken letter by letter,
+/// e.g. "FBI" or "HTML", but not "NATO"
+pub fn is_initialism(word: &str) -> bool {
+    let letters = word.strip_suffix(PLURAL_SUFFIX).unwrap_or(word);
+
+    letters.chars().count() > 1
+        && letters.chars().all(|c| c.is_ascii_uppercase())
+        && !WORD_ACRONYMS.contains(&letters)
+}
+
+/// Returns true if at least three quarters of the words are written in capitals,
+/// then they are not initialisms
+pub fn is_capitals_text(tokens: &[Token]) -> bool {
+    let words = tokens
+        .iter()
+        .filter(|token| token.is_word() && token.text().chars().any(char::is_alphabetic))
+        .collect::<Vec<&Token>>();
+
+    let capitals = words
+        .iter()
+        .filter(|token| !token.text().chars().any(char::is_lowercase))
+        .count();
+
+    words.len() >= MIN_CAPITALS_TEXT_WORDS && capitals * 4 >= words.len() * 3
+}
+
+/// Parse the initialism graphemes to sounds of the letter names
+pub fn parse_initialism(mut scanner: Scanner) -> Vec<Sound> {
+    let mut sounds = vec![];
+
+    while !scanner.is_done() {
+        let letter = scanner.pop();
+
+        let sound = match LETTER_NAMES.iter().find(|(name, _)| *name == letter) {
+            Some((_, kind)) => Sound::with_rule(
+                *kind,
+                letter.to_string(),
+                format!("initialism: letter name of {}", letter),
+            ),
+            None => Sound::new(SoundKind::Undefined, letter.to_string()),
+        };
+
+        sounds.push(sound);
+    }
+
+    sounds
+}
+
+#[cfg(test)]
+mod is_initialism {
+    use super::is_initialism;
+
+    #[test]
+    fn it_should_be_initialism() {
+        assert!(is_initialism("FBI"));
+        assert!(is_initialism("HTML"));
+        assert!(is_initialism("URLs"));
+    }
+
+    #[test]
+    fn it_should_not_be_initialism() {
+        assert!(!is_initialism("NATO"));
+        assert!(!is_initialism("ASAP"));
+        assert!(!is_initialism("I"));
+        assert!(!is_initialism("Html"));
+        assert!(!is_initialism("PhD"));
+    }
+}
+
+#[cfg(test)]
+mod is_capitals_text {
+    use super::is_capitals_text;
+    use crate::tokenizer::tokenize;
+
+    #[test]
+    fn it_should_be_capitals_text() {
+        assert!(is_capitals_text(&tokenize("PUT IT THERE, Tom")));
+    }
+
+    #[test]
+    fn it_should_not_be_capitals_text() {
+        assert!(!is_capitals_text(&tokenize("FBI")));
+        assert!(!is_capitals_text(&tokenize("The FBI and CIA")));
+        assert!(!is_capitals_text(&tokenize("The FBI, NATO and TV")));
+    }
+}
@@ -0,0 +1,307 @@
+use crate::options::Options;
+use crate::parser::parse_with;
+use crate::sound::Sound;
+
+/// Cost of the inserted or deleted sound
+const INDEL_COST: f64 = 1.0;
+
+/// Edit operation of the alignment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edit {
+    /// Sounds are the same
+    Match,
+    /// The sound of the first word is replaced with the sound of the second word
+    Substitution,
+    /// The sound of the second word is missed in the first word
+    Insertion,
+    /// The sound of the first word is missed in the second word
+    Deletion,
+}
+
+/// Pair of the aligned sounds, one of them is missed in the insertion or the deletion
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlignedSounds {
+    edit: Edit,
+    first: Option<String>,
+    second: Option<String>,
+    cost: f64,
+}
+
+impl AlignedSounds {
+    /// Returns edit operation
+    pub fn edit(&self) -> Edit {
+        self.edit
+    }
+
+    /// Returns text of the sound of the first word
+    pub fn first(&self) -> Option<&str> {
+        self.first.as_deref()
+    }
+
+    /// Returns text of the sound of the second word
+    pub fn second(&self) -> Option<&str> {
+        self.second.as_deref()
+    }
+
+    /// Returns cost of the edit
+    pub fn cost(&self) -> f64 {
+        self.cost
+    }
+}
+
+/// Phonetic distance between two words with the alignment of their sounds
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhoneticDistance {
+    distance: f64,
+    alignment: Vec<AlignedSounds>,
+}
+
+impl PhoneticDistance {
+    /// Returns the weighted edit distance, zero for the same sounds
+    pub fn distance(&self) -> f64 {
+        self.distance
+    }
+
+    /// Returns the distance divided by the number of the aligned sounds, from 0 to 1
+    pub fn normalized(&self) -> f64 {
+        match self.alignment.len() {
+            0 => 0.0,
+            len => self.distance / len as f64,
+        }
+    }
+
+    /// Returns the alignment of the sounds
+    pub fn alignment(&self) -> &[AlignedSounds] {
+        &self.alignment
+    }
+}
+
+/// Measure the edit distance between the sounds of the words,
+/// substitutions are weighted by articulatory features (voicing, place and manner)
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::{phonetic_distance, Edit};
+///
+/// let distance = phonetic_distance("think", "sink");
+///
+/// assert_eq!(distance.alignment()[0].edit(), Edit::Substitution);
+/// assert_eq!(distance.alignment()[0].first(), Some("th"));
+/// assert_eq!(distance.alignment()[0].second(), Some("s"));
+/// assert!(distance.distance() < phonetic_distance("think", "mink").distance());
+/// ```
+pub fn phonetic_distance<A: AsRef<str>, B: AsRef<str>>(first: A, second: B) -> PhoneticDistance {
+    phonetic_distance_with(first, second, &Options::default())
+}
+
+/// Measure the phonetic distance between the words with the options
+pub fn phonetic_distance_with<A: AsRef<str>, B: AsRef<str>>(
+    first: A,
+    second: B,
+    options: &Options,
+) -> PhoneticDistance {
+    let sounds_of = |text: &str| {
+        parse_with(text, options)
+            .into_iter()
+            .filter(|sound| !sound.text().trim().is_empty())
+            .collect::<Vec<Sound>>()
+    };
+
+    align(&sounds_of(first.as_ref()), &sounds_of(second.as_ref()))
+}
+
+/// Returns cost of the substitution of the sounds: the share of the different features
+fn substitution_cost(first: &Sound, second: &Sound) -> f64 {
+    // sounds of the kind with its own features are the same in any spelling, e.g. `Ch`
+    let is_same_sound = first.kind().features().is_some()
+        || first.text().to_lowercase() == second.text().to_lowercase();
+
+    if first.kind() == second.kind() && is_same_sound {
+        return 0.0;
+    }
+
+    // sounds with the same features differ only in spelling, e.g. "c" and "k"
+    match (first.features(), second.features()) {
+        (Some(first), Some(second)) => {
+            let differences = [
+                first.voicing != second.voicing,
+                first.place != second.place,
+                first.manner != second.manner,
+            ]
+            .iter()
+            .filter(|is_different| **is_different)
+            .count();
+
+            differences as f64 / 3.0
+        }
+        _ => INDEL_COST,
+    }
+}
+
+/// Aligns the sounds by the weighted Levenshtein distance
+pub(crate) fn align(first: &[Sound], second: &[Sound]) -> PhoneticDistance {
+    let (rows, columns) = (first.len() + 1, second.len() + 1);
+    let mut costs = vec![vec![0.0; columns]; rows];
+
+    for (i, row) in costs.iter_mut().enumerate() {
+        row[0] = i as f64 * INDEL_COST;
+    }
+
+    for (j, cost) in costs[0].iter_mut().enumerate() {
+        *cost = j as f64 * INDEL_COST;
+    }
+
+    for i in 1..rows {
+        for j in 1..columns {
+            costs[i][j] = (costs[i - 1][j - 1] + substitution_cost(&first[i - 1], &second[j - 1]))
+                .min(costs[i - 1][j] + INDEL_COST)
+                .min(costs[i][j - 1] + INDEL_COST);
+        }
+    }
+
+    let mut alignment = vec![];
+    let (mut i, mut j) = (first.len(), second.len());
+
+    while i > 0 || j > 0 {
+        let text = |sound: &Sound| Some(sound.text().to_string());
+
+        if i > 0 && j > 0 {
+            let cost = substitution_cost(&first[i - 1], &second[j - 1]);
+
+            if costs[i][j] == costs[i - 1][j - 1] + cost {
+                alignment.push(AlignedSounds {
+                    edit: match cost == 0.0 {
+                        true => Edit::Match,
+                        false => Edit::Substitution,
+                    },
+                    first: text(&first[i - 1]),
+                    second: text(&second[j - 1]),
+                    cost,
+                });
+
+                i -= 1;
+                j -= 1;
+
+                continue;
+            }
+        }
+
+        if i > 0 && costs[i][j] == costs[i - 1][j] + INDEL_COST {
+            alignment.push(AlignedSounds {
+                edit: Edit::Deletion,
+                first: text(&first[i - 1]),
+                second: None,
+                cost: INDEL_COST,
+            });
+
+            i -= 1;
+        } else {
+            alignment.push(AlignedSounds {
+                edit: Edit::Insertion,
+                first: None,
+                second: text(&second[j - 1]),
+                cost: INDEL_COST,
+            });
+
+            j -= 1;
+        }
+    }
+
+    alignment.reverse();
+
+    PhoneticDistance {
+        distance: costs[first.len()][second.len()],
+        alignment,
+    }
+}
+
+#[cfg(test)]
+mod phonetic_distance {
+    use super::{phonetic_distance, phonetic_distance_with, Edit, Options};
+    use crate::dialect::Dialect;
+
+    fn edits(first: &str, second: &str) -> Vec<(Edit, Option<String>, Option<String>)> {
+        phonetic_distance(first, second)
+            .alignment()
+            .iter()
+            .map(|aligned| {
+                (
+                    aligned.edit(),
+                    aligned.first().map(String::from),
+                    aligned.second().map(String::from),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn it_should_measure_same_words() {
+        let distance = phonetic_distance("Cat", "cat");
+
+        assert_eq!(distance.distance(), 0.0);
+        assert_eq!(distance.normalized(), 0.0);
+        assert_eq!(phonetic_distance("", "").alignment().len(), 0);
+    }
+
+    #[test]
+    fn it_should_weight_substitutions_by_features() {
+        // t and d differ in voicing only, t and m in all features
+        let voicing = phonetic_distance("tin", "din").distance();
+        let all = phonetic_distance("tin", "min").distance();
+
+        assert!((voicing - 1.0 / 3.0).abs() < 1e-9);
+        assert_eq!(all, 1.0);
+        assert_eq!(phonetic_distance("rice", "lice").distance(), 1.0 / 3.0);
+    }
+
+    #[test]
+    fn it_should_match_sounds_with_same_features() {
+        assert_eq!(phonetic_distance("cat", "kat").distance(), 0.0);
+        assert_eq!(edits("cat", "kat")[0].0, Edit::Match);
+        assert_eq!(phonetic_distance("bat", "bit").distance(), 0.0);
+    }
+
+    #[test]
+    fn it_should_align_insertions_and_deletions() {
+        assert_eq!(
+            edits("cast", "cat"),
+            vec![
+                (
+                    Edit::Match,
+                    Some(String::from("c")),
+                    Some(String::from("c"))
+                ),
+                (
+                    Edit::Match,
+                    Some(String::from("a")),
+                    Some(String::from("a"))
+                ),
+                (Edit::Deletion, Some(String::from("s")), None),
+                (
+                    Edit::Match,
+                    Some(String::from("t")),
+                    Some(String::from("t"))
+                ),
+            ]
+        );
+        assert_eq!(
+            edits("sing", "sting")[1],
+            (Edit::Insertion, None, Some(String::from("t")))
+        );
+    }
+
+    #[test]
+    fn it_should_measure_with_options() {
+        let options = Options {
+            dialect: Dialect::ReceivedPronunciation,
+            ..Options::default()
+        };
+
+        assert_eq!(phonetic_distance("tune", "chune").distance(), 2.0 / 3.0);
+        assert_eq!(
+            phonetic_distance_with("tune", "chune", &options).distance(),
+            0.0
+        );
+    }
+}
@@ -0,0 +1,387 @@
+use crate::aligner::{align, Edit};
+use crate::options::Options;
+use crate::parser::parse_words_with;
+use crate::sound::{Sound, SoundKind};
+use crate::word::Word;
+use std::fmt;
+use std::ops::Range;
+
+/// Pronunciation error of the learner: substituted, deleted or inserted sound
+#[derive(Debug, PartialEq)]
+pub struct Mispronunciation {
+    edit: Edit,
+    expected: Option<Sound>,
+    realized: Option<Sound>,
+    word: String,
+    span: Range<usize>,
+}
+
+impl Mispronunciation {
+    /// Returns edit operation: `Substitution`, `Deletion` or `Insertion`
+    pub fn edit(&self) -> Edit {
+        self.edit
+    }
+
+    /// Returns the sound of the target text, missed in the insertion
+    pub fn expected(&self) -> Option<&Sound> {
+        self.expected.as_ref()
+    }
+
+    /// Returns the recognized sound, missed in the deletion
+    pub fn realized(&self) -> Option<&Sound> {
+        self.realized.as_ref()
+    }
+
+    /// Returns kind of the expected sound or of the inserted one
+    pub fn kind(&self) -> SoundKind {
+        match (&self.expected, &self.realized) {
+            (Some(sound), _) | (None, Some(sound)) => *sound.kind(),
+            (None, None) => SoundKind::Undefined,
+        }
+    }
+
+    /// Returns the target word of the error
+    pub fn word(&self) -> &str {
+        &self.word
+    }
+
+    /// Returns byte span of the expected sound in the target text, empty for the insertion
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Returns the error without the word, e.g. "Th realized as S"
+    pub(crate) fn description(&self) -> String {
+        match (&self.expected, &self.realized) {
+            (Some(expected), Some(realized)) => {
+                format!("{} realized as {}", label(expected), label(realized))
+            }
+            (Some(expected), None) => format!("{} deleted", label(expected)),
+            (None, Some(realized)) => format!("{} inserted", label(realized)),
+            (None, None) => String::new(),
+        }
+    }
+}
+
+impl fmt::Display for Mispronunciation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} in '{}'", self.description(), self.word)
+    }
+}
+
+/// Comparison of the target text with the recognized speech of the learner
+#[derive(Debug, PartialEq)]
+pub struct Assessment {
+    sounds: usize,
+    mispronunciations: Vec<Mispronunciation>,
+}
+
+impl Assessment {
+    /// Returns number of the sounds of the target text
+    pub fn sounds(&self) -> usize {
+        self.sounds
+    }
+
+    /// Returns the errors in the order of the target text
+    pub fn mispronunciations(&self) -> &[Mispronunciation] {
+        &self.mispronunciations
+    }
+
+    /// Returns the errors of the sound kind
+    pub fn kind(&self, kind: SoundKind) -> Vec<&Mispronunciation> {
+        self.mispronunciations
+            .iter()
+            .filter(|mispronunciation| mispronunciation.kind() == kind)
+            .collect()
+    }
+
+    /// Returns share of the target sounds pronounced as expected, from 0 to 1
+    pub fn accuracy(&self) -> f64 {
+        let missed = self
+            .mispronunciations
+            .iter()
+            .filter(|mispronunciation| mispronunciation.edit != Edit::Insertion)
+            .count();
+
+        match self.sounds {
+            0 => 1.0,
+            sounds => (sounds - missed) as f64 / sounds as f64,
+        }
+    }
+}
+
+/// Compare the target text with the recognized text of the learner's speech
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::{assess, Edit, SoundKind};
+///
+/// let assessment = assess("I think so", "I sink so");
+///
+/// assert_eq!(assessment.mispronunciations().len(), 1);
+/// assert_eq!(assessment.mispronunciations()[0].edit(), Edit::Substitution);
+/// assert_eq!(assessment.mispronunciations()[0].to_string(), "Th realized as s in 'think'");
+/// assert_eq!(assessment.kind(SoundKind::Th).len(), 1);
+/// ```
+pub fn assess<T: AsRef<str>, R: AsRef<str>>(target: T, recognized: R) -> Assessment {
+    assess_with(target, recognized, &Options::default())
+}
+
+/// Compare the target text with the recognized text of the learner's speech with the options
+pub fn assess_with<T: AsRef<str>, R: AsRef<str>>(
+    target: T,
+    recognized: R,
+    options: &Options,
+) -> Assessment {
+    let recognized = parse_words_with(recognized, options)
+        .into_iter()
+        .filter(|word| !word.is_separator())
+        .flat_map(Word::into_sounds)
+        .collect::<Vec<Sound>>();
+
+    assess_sounds_with(target, &recognized, options)
+}
+
+/// Compare the target text with the recognized sounds, e.g. mapped from the phonemes of the speech recognizer
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::{assess_sounds_with, Options, Sound, SoundKind};
+///
+/// let recognized = vec![
+///     Sound::new(SoundKind::Undefined, String::from("s")),
+///     Sound::new(SoundKind::Undefined, String::from("i")),
+///     Sound::new(SoundKind::Undefined, String::from("n")),
+/// ];
+///
+/// let assessment = assess_sounds_with("thin", &recognized, &Options::default());
+///
+/// assert_eq!(assessment.mispronunciations()[0].to_string(), "Th realized as s in 'thin'");
+/// ```
+pub fn assess_sounds_with<T: AsRef<str>>(
+    target: T,
+    recognized: &[Sound],
+    options: &Options,
+) -> Assessment {
+    assess_words(&parse_words_with(target, options), recognized)
+}
+
+/// Aligns the sounds of the target words with the recognized sounds
+pub(crate) fn assess_words(words: &[Word], recognized: &[Sound]) -> Assessment {
+    let mut expected: Vec<(&Sound, &Word, Range<usize>)> = vec![];
+
+    for word in words.iter().filter(|word| !word.is_separator()) {
+        // sounds of the expanded word, e.g. "3rd", have no own spans in the source text
+        for (sound, span) in word.sounds().iter().zip(word.sound_spans()) {
+            if is_spoken(sound) {
+                expected.push((sound, word, span));
+            }
+        }
+    }
+
+    let recognized = recognized
+        .iter()
+        .filter(|sound| is_spoken(sound))
+        .collect::<Vec<&Sound>>();
+    let sounds = expected
+        .iter()
+        .map(|(sound, _, _)| copy(sound))
+        .collect::<Vec<Sound>>();
+    let alignment = align(
+        &sounds,
+        &recognized
+            .iter()
+            .map(|sound| copy(sound))
+            .collect::<Vec<Sound>>(),
+    );
+
+    let mut mispronunciations = vec![];
+    let (mut i, mut j) = (0, 0);
+
+    for aligned in alignment.alignment() {
+        let edit = aligned.edit();
+
+        let mispronunciation = match edit {
+            Edit::Match => None,
+            Edit::Substitution | Edit::Deletion => {
+                let (sound, word, span) = &expected[i];
+
+                Some(Mispronunciation {
+                    edit,
+                    expected: Some(copy(sound)),
+                    realized: match edit {
+                        Edit::Substitution => Some(copy(recognized[j])),
+                        _ => None,
+                    },
+                    word: word.text().to_string(),
+                    span: span.clone(),
+                })
+            }
+            // the inserted sound belongs to the word of the previous sound, or the first one
+            Edit::Insertion => {
+                let (word, position) = match i.checked_sub(1).and_then(|i| expected.get(i)) {
+                    Some((_, word, span)) => (word.text(), span.end),
+                    None => match expected.first() {
+                        Some((_, word, span)) => (word.text(), span.start),
+                        None => ("", 0),
+                    },
+                };
+
+                Some(Mispronunciation {
+                    edit,
+                    expected: None,
+                    realized: Some(copy(recognized[j])),
+                    word: word.to_string(),
+                    span: position..position,
+                })
+            }
+        };
+
+        mispronunciations.extend(mispronunciation);
+
+        if edit != Edit::Insertion {
+            i += 1;
+        }
+
+        if edit != Edit::Deletion {
+            j += 1;
+        }
+    }
+
+    Assessment {
+        sounds: expected.len(),
+        mispronunciations,
+    }
+}
+
+/// Returns new sound of the same kind and text
+fn copy(sound: &Sound) -> Sound {
+    Sound::new(*sound.kind(), sound.text().to_string())
+}
+
+/// Returns true if the sound is spoken, punctuation and digits without letters are not, e.g. "'" in "don't"
+fn is_spoken(sound: &Sound) -> bool {
+    *sound.kind() != SoundKind::Undefined || sound.text().chars().any(char::is_alphabetic)
+}
+
+/// Returns name of the sound kind or the letters of the undefined sound as written, e.g. "Th" or "é"
+fn label(sound: &Sound) -> String {
+    match sound.kind() {
+        SoundKind::Undefined => sound.text().to_string(),
+        kind => format!("{:?}", kind),
+    }
+}
+
+#[cfg(test)]
+mod assess {
+    use super::{assess, assess_with, Edit, Options};
+    use crate::sound::SoundKind;
+
+    fn errors(target: &str, recognized: &str) -> Vec<String> {
+        assess(target, recognized)
+            .mispronunciations()
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn it_should_report_substitutions() {
+        assert_eq!(
+            errors("The very thin man", "Ze wery sin man"),
+            vec![
+                String::from("Th realized as Z in 'The'"),
+                String::from("V realized as W in 'very'"),
+                String::from("Th realized as s in 'thin'"),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_report_deletions_and_insertions() {
+        assert_eq!(
+            errors("last night", "las night"),
+            vec![String::from("Ptk deleted in 'last'")]
+        );
+        assert_eq!(
+            errors("school", "eschool"),
+            vec![String::from("e inserted in 'school'")]
+        );
+    }
+
+    #[test]
+    fn it_should_skip_punctuation_and_digits() {
+        assert!(errors("don't", "dont").is_empty());
+        assert!(errors("Wait, 2 cats!", "wait cats").is_empty());
+        assert_eq!(assess("don't", "dont").sounds(), 4);
+    }
+
+    #[test]
+    fn it_should_match_spelling_variants() {
+        assert!(errors("cat", "kat").is_empty());
+        assert!(errors("café", "cafe").is_empty());
+        assert_eq!(
+            errors("café", "cave"),
+            vec![String::from("f realized as v in 'café'")]
+        );
+    }
+
+    #[test]
+    fn it_should_group_errors_by_kind() {
+        let assessment = assess("think thank", "sink tank");
+
+        assert_eq!(assessment.kind(SoundKind::Th).len(), 2);
+        assert_eq!(assessment.kind(SoundKind::V).len(), 0);
+        assert_eq!(assessment.kind(SoundKind::Th)[1].span(), 6..8);
+        assert_eq!(
+            assessment.kind(SoundKind::Th)[1].realized().unwrap().text(),
+            "t"
+        );
+    }
+
+    #[test]
+    fn it_should_measure_accuracy() {
+        assert_eq!(assess("cat", "cat").accuracy(), 1.0);
+        assert!(assess("cat", "cat").mispronunciations().is_empty());
+        assert_eq!(assess("", "").accuracy(), 1.0);
+        assert_eq!(assess("thin", "sin").accuracy(), 2.0 / 3.0);
+        assert_eq!(
+            assess("thin", "").mispronunciations()[0].edit(),
+            Edit::Deletion
+        );
+    }
+
+    #[test]
+    fn it_should_assess_with_options() {
+        let options = Options {
+            expand: true,
+            ..Options::default()
+        };
+
+        let assessment = assess_with("the 3rd", "the sird", &options);
+
+        assert_eq!(
+            assessment.mispronunciations()[0].to_string(),
+            "Th realized as s in '3rd'"
+        );
+        assert_eq!(assessment.mispronunciations()[0].span(), 4..7);
+    }
+
+    #[test]
+    fn it_should_report_source_spans_with_nfc() {
+        let options = Options {
+            nfc: true,
+            ..Options::default()
+        };
+
+        let assessment = assess_with("ca\u{301}t", "ca\u{301}d", &options);
+
+        assert_eq!(
+            assessment.mispronunciations()[0].to_string(),
+            "Ptk realized as d in 'ca\u{301}t'"
+        );
+        assert_eq!(assessment.mispronunciations()[0].span(), 4..5);
+    }
+}
@@ -0,0 +1,275 @@
+use crate::dialect::Dialect;
+use crate::options::Options;
+use crate::sound::SoundKind;
+use crate::word::Word;
+use std::ops::Range;
+
+/// Words that start with /j/ and coalesce with the final d or t of the previous word
+const YOU_WORDS: [&str; 5] = ["you", "your", "yours", "you're", "yourself"];
+
+/// Word pairs reduced in casual speech with their spoken forms
+const REDUCTIONS: [(&str, &str, &str); 4] = [
+    ("want", "to", "wanna"),
+    ("going", "to", "gonna"),
+    ("got", "to", "gotta"),
+    ("kind", "of", "kinda"),
+];
+
+/// Consonants before the final t or d that is elided before a consonant, e.g. "last night"
+const ELISION_CONSONANTS: [char; 7] = ['s', 'f', 'k', 'c', 'p', 'x', 'n'];
+
+/// Letters of the vowels at the start of the word
+const VOWELS: [char; 5] = ['a', 'e', 'i', 'o', 'u'];
+
+/// Kinds of the sound changes across the word boundary
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkKind {
+    /// Final d or t merges with the following /j/, e.g. "did you" and "don't you"
+    Coalescence,
+    /// Words are reduced to one spoken form, e.g. "want to" is "wanna"
+    Reduction,
+    /// Final r is pronounced before the vowel in the non-rhotic Received Pronunciation, e.g. "far away"
+    LinkingR,
+    /// Final t or d between consonants is dropped, e.g. "last night"
+    Elision,
+}
+
+/// Sound change across the word boundary.
+///
+/// Links are kept apart from the words: a link belongs to two words and the separator between them,
+/// so neither `Word` nor `Sound` can own it, and the words stay the same with or without
+/// the connected speech. The link refers to its words by `words` indices instead.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Link {
+    kind: LinkKind,
+    text: String,
+    span: Range<usize>,
+    words: Range<usize>,
+    sound: SoundKind,
+    description: String,
+}
+
+impl Link {
+    /// Returns kind of the link
+    pub fn kind(&self) -> LinkKind {
+        self.kind
+    }
+
+    /// Returns source text of the link, it spans the word boundary
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns byte span of the link in the source text
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Returns indices of the linked words (with the separator between them) in the parsed words
+    pub fn words(&self) -> Range<usize> {
+        self.words.clone()
+    }
+
+    /// Returns kind of the sound the link results in, e.g. `Dj` in "did you"
+    pub fn sound(&self) -> SoundKind {
+        self.sound
+    }
+
+    /// Returns description of the sound change, e.g. "want to → wanna"
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+}
+
+/// Find sound changes between the words of the text separated by spaces
+pub fn connect(text: &str, words: &[Word], options: &Options) -> Vec<Link> {
+    let mut links = vec![];
+
+    for (index, first) in words.iter().enumerate() {
+        let (separator, second) = match (words.get(index + 1), words.get(index + 2)) {
+            (Some(separator), Some(second)) => (separator, second),
+            _ => break,
+        };
+
+        let is_linked = !first.is_separator()
+            && !first.is_expanded()
+            && !second.is_separator()
+            && !second.is_expanded()
+            && separator.text().chars().all(|c| c == ' ');
+
+        if !is_linked {
+            continue;
+        }
+
+        if let Some((kind, span, sound, description)) = find_link(first, second, options) {
+            links.push(Link {
+                kind,
+                text: text[span.clone()].to_string(),
+                span,
+                words: index..index + 3,
+                sound,
+                description,
+            });
+        }
+    }
+
+    links
+}
+
+/// Returns the sound change between two words: kind, span, resulting sound and description
+fn find_link(
+    first: &Word,
+    second: &Word,
+    options: &Options,
+) -> Option<(LinkKind, Range<usize>, SoundKind, String)> {
+    let (left, right) = (first.normalized(), second.normalized());
+    let last = left.chars().last()?;
+
+    if let Some((_, _, spoken)) = REDUCTIONS
+        .iter()
+        .find(|(first, second, _)| *first == left && *second == right)
+    {
+        return Some((
+            LinkKind::Reduction,
+            first.span().start..second.span().end,
+            SoundKind::Undefined,
+            format!("{} {} → {}", left, right, spoken),
+        ));
+    }
+
+    let span_of_last = |letters: usize| first.span().end - letters..first.span().end;
+
+    if YOU_WORDS.contains(&right) && matches!(last, 'd' | 't') {
+        let sound = match last {
+            'd' => SoundKind::Dj,
+            _ => SoundKind::Ch,
+        };
+
+        return Some((
+            LinkKind::Coalescence,
+            span_of_last(1).start..second.span().start + 1,
+            sound,
+            format!("final {} and y → {:?}", last, sound),
+        ));
+    }
+
+    let first_letter = right.chars().next()?;
+    let before_last = left.chars().rev().nth(1);
+
+    let is_elision = matches!(last, 't' | 'd')
+        && before_last.is_some_and(|c| ELISION_CONSONANTS.contains(&c))
+        && first_letter.is_ascii_alphabetic()
+        && !VOWELS.contains(&first_letter);
+
+    if is_elision {
+        return Some((
+            LinkKind::Elision,
+            span_of_last(1),
+            SoundKind::Undefined,
+            format!("final {} between consonants is elided", last),
+        ));
+    }
+
+    // the final r is always pronounced in the rhotic accents, so there is nothing to link
+    if options.dialect != Dialect::ReceivedPronunciation {
+        return None;
+    }
+
+    let linking_r = match (left.ends_with('r'), left.ends_with("re")) {
+        (true, _) => Some(1),
+        (_, true) => Some(2),
+        _ => None,
+    };
+
+    match linking_r {
+        Some(letters) if VOWELS.contains(&first_letter) => Some((
+            LinkKind::LinkingR,
+            span_of_last(letters).start..second.span().start + 1,
+            SoundKind::Undefined,
+            String::from("final r is linked to the vowel"),
+        )),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod connect {
+    use super::{connect, Dialect, LinkKind, Options, SoundKind};
+    use crate::parser::parse_words;
+
+    fn links(text: &str) -> Vec<(LinkKind, String, SoundKind)> {
+        links_with(text, &Options::default())
+    }
+
+    fn links_with(text: &str, options: &Options) -> Vec<(LinkKind, String, SoundKind)> {
+        connect(text, &parse_words(text), options)
+            .into_iter()
+            .map(|link| (link.kind(), link.text().to_string(), link.sound()))
+            .collect()
+    }
+
+    #[test]
+    fn it_should_connect_coalescence() {
+        assert_eq!(
+            links("Did you see? Don't you know"),
+            vec![
+                (LinkKind::Coalescence, String::from("d y"), SoundKind::Dj),
+                (LinkKind::Coalescence, String::from("t y"), SoundKind::Ch),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_connect_reduction() {
+        let links = connect(
+            "I want to go",
+            &parse_words("I want to go"),
+            &Options::default(),
+        );
+
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].kind(), LinkKind::Reduction);
+        assert_eq!(links[0].span(), 2..9);
+        assert_eq!(links[0].words(), 2..5);
+        assert_eq!(links[0].description(), "want to → wanna");
+    }
+
+    #[test]
+    fn it_should_connect_elision_and_linking_r() {
+        let options = Options {
+            dialect: Dialect::ReceivedPronunciation,
+            ..Options::default()
+        };
+
+        assert_eq!(
+            links_with("last night, far away or here it is", &options),
+            vec![
+                (LinkKind::Elision, String::from("t"), SoundKind::Undefined),
+                (
+                    LinkKind::LinkingR,
+                    String::from("r a"),
+                    SoundKind::Undefined
+                ),
+                (
+                    LinkKind::LinkingR,
+                    String::from("re i"),
+                    SoundKind::Undefined
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_not_link_r_in_general_american() {
+        assert_eq!(
+            links("last night, far away or here it is"),
+            vec![(LinkKind::Elision, String::from("t"), SoundKind::Undefined)]
+        );
+    }
+
+    #[test]
+    fn it_should_not_connect_across_punctuation() {
+        assert!(links("Did, you? Last. Night").is_empty());
+        assert!(links("just in case").is_empty());
+    }
+}
@@ -0,0 +1,323 @@
+use crate::error::Error;
+use crate::options::Options;
+use crate::parser::parse_with;
+use crate::sound::{Sound, SoundKind};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// Separator between the sounds of the contrast, e.g. "V/W"
+const CONTRAST_SEPARATOR: char = '/';
+
+/// Sound of the contrast: a sound kind or letters of undefined sounds
+#[derive(Debug, Clone, PartialEq)]
+enum Side {
+    Kind(SoundKind),
+    Letters(String),
+}
+
+impl Side {
+    /// Returns number of the sounds matched from the index, if they match
+    fn matches(&self, sounds: &[Sound], index: usize) -> Option<usize> {
+        match self {
+            Side::Kind(kind) => sounds
+                .get(index)
+                .filter(|sound| sound.kind() == kind)
+                .map(|_| 1),
+            Side::Letters(letters) => {
+                let mut text = String::new();
+
+                for (len, sound) in sounds[index..].iter().enumerate() {
+                    text.push_str(&sound.text().to_lowercase());
+
+                    if !letters.starts_with(&text) {
+                        return None;
+                    }
+
+                    if text == *letters {
+                        return Some(len + 1);
+                    }
+                }
+
+                None
+            }
+        }
+    }
+}
+
+impl fmt::Display for Side {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Side::Kind(kind) => write!(f, "{:?}", kind),
+            Side::Letters(letters) => write!(f, "{}", letters),
+        }
+    }
+}
+
+impl FromStr for Side {
+    type Err = Error;
+
+    fn from_str(side: &str) -> Result<Self, Self::Err> {
+        match side.parse::<SoundKind>() {
+            Ok(SoundKind::Undefined) => Err(Error::InvalidContrast(side.to_string())),
+            Ok(kind) => Ok(Side::Kind(kind)),
+            Err(_) if !side.is_empty() && side.chars().all(char::is_alphabetic) => {
+                Ok(Side::Letters(side.to_lowercase()))
+            }
+            Err(_) => Err(Error::InvalidContrast(side.to_string())),
+        }
+    }
+}
+
+/// Contrast of two sounds for the minimal pairs, e.g. `V/W` for "vet" and "wet"
+///
+/// Sides are names of the sound kinds (`Th`) or letters of undefined sounds (`s`, `sh`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Contrast {
+    first: Side,
+    second: Side,
+}
+
+impl fmt::Display for Contrast {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}{}", self.first, CONTRAST_SEPARATOR, self.second)
+    }
+}
+
+impl FromStr for Contrast {
+    type Err = Error;
+
+    /// Parses the contrast from the text format, e.g. "Th/s"
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let (first, second) = source
+            .split_once(CONTRAST_SEPARATOR)
+            .ok_or_else(|| Error::InvalidContrast(source.to_string()))?;
+
+        let contrast = Contrast {
+            first: first.trim().parse()?,
+            second: second.trim().parse()?,
+        };
+
+        match contrast.first == contrast.second {
+            true => Err(Error::InvalidContrast(source.to_string())),
+            false => Ok(contrast),
+        }
+    }
+}
+
+/// Pair of words that differ only in the sounds of the contrast
+#[derive(Debug, Clone, PartialEq)]
+pub struct MinimalPair {
+    first: String,
+    second: String,
+}
+
+impl MinimalPair {
+    /// Returns the word with the first sound of the contrast
+    pub fn first(&self) -> &str {
+        &self.first
+    }
+
+    /// Returns the word with the second sound of the contrast
+    pub fn second(&self) -> &str {
+        &self.second
+    }
+}
+
+impl fmt::Display for MinimalPair {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}\t{}", self.first, self.second)
+    }
+}
+
+/// Find minimal pairs of the contrast in the word list
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::{minimal_pairs, Contrast};
+///
+/// let contrast = "Th/s".parse::<Contrast>().unwrap();
+/// let pairs = minimal_pairs(["sink", "thin", "think", "sin", "tin"], &contrast);
+///
+/// assert_eq!(pairs.len(), 2);
+/// assert_eq!((pairs[0].first(), pairs[0].second()), ("thin", "sin"));
+/// assert_eq!(pairs[1].to_string(), "think\tsink");
+/// ```
+pub fn minimal_pairs<I>(words: I, contrast: &Contrast) -> Vec<MinimalPair>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    minimal_pairs_with(words, contrast, &Options::default())
+}
+
+/// Find minimal pairs of the contrast in the word list with the options
+pub fn minimal_pairs_with<I>(words: I, contrast: &Contrast, options: &Options) -> Vec<MinimalPair>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut seconds: HashMap<String, Vec<String>> = HashMap::new();
+    let mut firsts: Vec<(String, String)> = vec![];
+
+    for word in words {
+        let word = word.as_ref().trim();
+
+        if word.is_empty() {
+            continue;
+        }
+
+        let sounds = parse_with(word, options);
+
+        for key in frames(&sounds, &contrast.first) {
+            firsts.push((key, word.to_string()));
+        }
+
+        for key in frames(&sounds, &contrast.second) {
+            let words = seconds.entry(key).or_default();
+
+            if !words.iter().any(|second| second == word) {
+                words.push(word.to_string());
+            }
+        }
+    }
+
+    let mut pairs: Vec<MinimalPair> = vec![];
+
+    for (key, first) in firsts {
+        for second in seconds.get(&key).into_iter().flatten() {
+            let pair = MinimalPair {
+                first: first.clone(),
+                second: second.clone(),
+            };
+
+            if first != *second && !pairs.contains(&pair) {
+                pairs.push(pair);
+            }
+        }
+    }
+
+    pairs
+}
+
+/// Returns the sounds around every match of the side, the sound itself is replaced with "_"
+fn frames(sounds: &[Sound], side: &Side) -> Vec<String> {
+    let key_of = |sounds: &[Sound]| {
+        sounds
+            .iter()
+            .map(|sound| format!("{:?}:{}", sound.kind(), sound.text().to_lowercase()))
+            .collect::<Vec<String>>()
+            .join(" ")
+    };
+
+    (0..sounds.len())
+        .filter_map(|index| {
+            side.matches(sounds, index).map(|len| {
+                format!(
+                    "{} _ {}",
+                    key_of(&sounds[..index]),
+                    key_of(&sounds[index + len..])
+                )
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod contrast_from_str {
+    use super::{Contrast, Error};
+
+    #[test]
+    fn it_should_parse_contrast() {
+        assert_eq!("V/W".parse::<Contrast>().unwrap().to_string(), "V/W");
+        assert_eq!("Ch / SH".parse::<Contrast>().unwrap().to_string(), "Ch/sh");
+    }
+
+    #[test]
+    fn it_should_report_invalid_contrast() {
+        assert_eq!(
+            "Th".parse::<Contrast>(),
+            Err(Error::InvalidContrast(String::from("Th")))
+        );
+        assert_eq!(
+            "Th/Undefined".parse::<Contrast>(),
+            Err(Error::InvalidContrast(String::from("Undefined")))
+        );
+        assert_eq!(
+            "s/s".parse::<Contrast>(),
+            Err(Error::InvalidContrast(String::from("s/s")))
+        );
+        assert_eq!(
+            "Th/1".parse::<Contrast>(),
+            Err(Error::InvalidContrast(String::from("1")))
+        );
+    }
+}
+
+#[cfg(test)]
+mod minimal_pairs {
+    use super::{minimal_pairs, minimal_pairs_with, Contrast, Options};
+    use crate::dialect::Dialect;
+
+    fn pairs(words: &[&str], contrast: &str) -> Vec<(String, String)> {
+        minimal_pairs(words, &contrast.parse::<Contrast>().unwrap())
+            .into_iter()
+            .map(|pair| (pair.first().to_string(), pair.second().to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn it_should_find_pairs_of_kinds() {
+        assert_eq!(
+            pairs(&["wet", "vet", "wine", "vine", "wail", "veil"], "V/W"),
+            vec![
+                (String::from("vet"), String::from("wet")),
+                (String::from("vine"), String::from("wine")),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_find_pairs_of_letters() {
+        assert_eq!(
+            pairs(&["chip", "ship", "chop", "shop", "chin", "shape"], "Ch/sh"),
+            vec![
+                (String::from("chip"), String::from("ship")),
+                (String::from("chop"), String::from("shop")),
+            ]
+        );
+        assert_eq!(
+            pairs(
+                &["thick", "sick", "thank", "sank", "mouth", "mouse"],
+                "Th/s"
+            ),
+            vec![
+                (String::from("thick"), String::from("sick")),
+                (String::from("thank"), String::from("sank")),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_not_pair_different_sounds_around() {
+        // "t" of "tin" is aspirated, "t" of "sting" is not
+        assert!(pairs(&["thin", "sting"], "Th/s").is_empty());
+        assert!(pairs(&[], "Th/s").is_empty());
+    }
+
+    #[test]
+    fn it_should_find_pairs_with_options() {
+        let options = Options {
+            dialect: Dialect::ReceivedPronunciation,
+            ..Options::default()
+        };
+        let contrast = "Ch/Ptk".parse::<Contrast>().unwrap();
+
+        assert!(minimal_pairs(["tube", "cube"], &contrast).is_empty());
+        assert_eq!(
+            minimal_pairs_with(["tube", "cube"], &contrast, &options)[0].to_string(),
+            "tube\tcube"
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use crate::lexicon::Lexicon;
+use crate::rules::RuleSet;
+use std::sync::OnceLock;
+
+/// Bundled General American rules, checked before the English rules
+const GENERAL_AMERICAN_RULES: &str = include_str!("../data/en-us.rules");
+
+/// Bundled Received Pronunciation rules, checked before the English rules
+const RECEIVED_PRONUNCIATION_RULES: &str = include_str!("../data/en-gb.rules");
+
+/// Bundled Received Pronunciation lexicon
+const RECEIVED_PRONUNCIATION_LEXICON: &str = include_str!("../data/en-gb.lexicon");
+
+/// English accents with their own rules and lexicons
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::{highlight_with, Dialect, Options};
+///
+/// let options = Options {
+///     dialect: Dialect::ReceivedPronunciation,
+///     ..Options::default()
+/// };
+///
+/// assert_eq!(highlight_with("tune", &Options::default()), "<span class='Ptk'>t</span>une");
+/// assert_eq!(highlight_with("tune", &options), "<span class='Ch'>t</span>une");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Dialect {
+    /// General American with t-flapping in "butter" and "city"
+    #[default]
+    GeneralAmerican,
+    /// Received Pronunciation with yod-coalescence in "tune" and the glottal stop in "button"
+    ReceivedPronunciation,
+}
+
+impl Dialect {
+    /// Returns the bundled rules of the dialect followed by the English rules
+    pub fn rules(&self) -> &'static RuleSet {
+        static GENERAL_AMERICAN: OnceLock<RuleSet> = OnceLock::new();
+        static RECEIVED_PRONUNCIATION: OnceLock<RuleSet> = OnceLock::new();
+
+        match self {
+            Dialect::GeneralAmerican => {
+                GENERAL_AMERICAN.get_or_init(|| with_english_rules(GENERAL_AMERICAN_RULES))
+            }
+            Dialect::ReceivedPronunciation => RECEIVED_PRONUNCIATION
+                .get_or_init(|| with_english_rules(RECEIVED_PRONUNCIATION_RULES)),
+        }
+    }
+
+    /// Returns the bundled lexicon of the dialect
+    pub fn lexicon(&self) -> &'static Lexicon {
+        static GENERAL_AMERICAN: OnceLock<Lexicon> = OnceLock::new();
+        static RECEIVED_PRONUNCIATION: OnceLock<Lexicon> = OnceLock::new();
+
+        match self {
+            Dialect::GeneralAmerican => GENERAL_AMERICAN.get_or_init(Lexicon::new),
+            Dialect::ReceivedPronunciation => RECEIVED_PRONUNCIATION.get_or_init(|| {
+                RECEIVED_PRONUNCIATION_LEXICON
+                    .parse()
+                    .expect("bundled lexicon is valid")
+            }),
+        }
+    }
+}
+
+/// Parses the bundled rules of the dialect and appends the English rules to them
+fn with_english_rules(source: &str) -> RuleSet {
+    let mut rules: RuleSet = source.parse().expect("bundled rules are valid");
+
+    for rule in RuleSet::english().rules() {
+        rules.push(rule.clone());
+    }
+
+    rules
+}
+
+#[cfg(test)]
+mod bundled {
+    use super::Dialect;
+
+    #[test]
+    fn it_should_parse_bundled_data() {
+        for dialect in [Dialect::GeneralAmerican, Dialect::ReceivedPronunciation] {
+            assert!(!dialect.rules().is_empty());
+        }
+
+        assert!(Dialect::GeneralAmerican.lexicon().is_empty());
+        assert!(!Dialect::ReceivedPronunciation.lexicon().is_empty());
+    }
+}
@@ -0,0 +1,1045 @@
+/// Length of the Soundex code
+const SOUNDEX_LENGTH: usize = 4;
+
+/// The most length of the Metaphone and Double Metaphone codes
+const METAPHONE_LENGTH: usize = 4;
+
+/// Vowels of the Metaphone
+const METAPHONE_VOWELS: &str = "AEIOU";
+
+/// Front vowels that soften C and G in the Metaphone
+const FRONT_VOWELS: &str = "EIY";
+
+/// Letters after which H is silent in the Metaphone
+const SILENT_H_AFTER: &str = "CSPTG";
+
+/// Vowels of the Double Metaphone
+const DOUBLE_METAPHONE_VOWELS: &str = "AEIOUY";
+
+/// Silent starts of the word in the Double Metaphone, e.g. "Knight"
+const SILENT_STARTS: [&str; 5] = ["GN", "KN", "PN", "WR", "PS"];
+
+/// Encode the word with American Soundex, e.g. "R163" for "Robert"
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::soundex;
+///
+/// assert_eq!(soundex("Robert"), "R163");
+/// assert_eq!(soundex("Rupert"), "R163");
+/// assert_eq!(soundex("Tymczak"), "T522");
+/// ```
+pub fn soundex<T: AsRef<str>>(word: T) -> String {
+    let letters = word
+        .as_ref()
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|c| c.to_ascii_uppercase())
+        .collect::<Vec<char>>();
+
+    let first = match letters.first() {
+        Some(first) => *first,
+        None => return String::new(),
+    };
+
+    let mut code = first.to_string();
+    let mut last = soundex_digit(first);
+
+    for letter in &letters[1..] {
+        match (letter, soundex_digit(*letter)) {
+            // H and W do not separate the letters with the same code, e.g. "Ashcraft"
+            ('H' | 'W', _) => {}
+            (_, None) => last = None,
+            (_, digit) if digit != last => {
+                code.extend(digit);
+                last = digit;
+            }
+            _ => {}
+        }
+
+        if code.len() == SOUNDEX_LENGTH {
+            break;
+        }
+    }
+
+    format!("{:0<width$}", code, width = SOUNDEX_LENGTH)
+}
+
+fn soundex_digit(letter: char) -> Option<char> {
+    match letter {
+        'B' | 'F' | 'P' | 'V' => Some('1'),
+        'C' | 'G' | 'J' | 'K' | 'Q' | 'S' | 'X' | 'Z' => Some('2'),
+        'D' | 'T' => Some('3'),
+        'L' => Some('4'),
+        'M' | 'N' => Some('5'),
+        'R' => Some('6'),
+        _ => None,
+    }
+}
+
+/// Encode the word with the original Metaphone, the code has at most four letters, "0" is "th"
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::metaphone;
+///
+/// assert_eq!(metaphone("Thumb"), "0M");
+/// assert_eq!(metaphone("knight"), "NT");
+/// ```
+pub fn metaphone<T: AsRef<str>>(word: T) -> String {
+    let mut letters = word
+        .as_ref()
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|c| c.to_ascii_uppercase())
+        .collect::<Vec<char>>();
+
+    if letters.len() < 2 {
+        return letters.iter().collect();
+    }
+
+    // initial letters that are silent or change the next one
+    match (letters[0], letters[1]) {
+        ('K' | 'G' | 'P', 'N') | ('A', 'E') | ('W', 'R') => {
+            letters.remove(0);
+        }
+        ('W', 'H') => {
+            letters.remove(1);
+        }
+        ('X', _) => letters[0] = 'S',
+        _ => {}
+    }
+
+    let word = Letters(&letters);
+    let len = letters.len();
+    let mut code = String::new();
+    let mut n = 0;
+
+    while code.len() < METAPHONE_LENGTH && n < len {
+        let letter = letters[n];
+
+        if letter != 'C' && word.is_at(n as isize - 1, letter) {
+            n += 1;
+
+            continue;
+        }
+
+        let is_last = n + 1 == len;
+        let is_next_front_vowel = word.is_any_at(n as isize + 1, FRONT_VOWELS);
+
+        match letter {
+            'A' | 'E' | 'I' | 'O' | 'U' => {
+                if n == 0 {
+                    code.push(letter);
+                }
+            }
+            'B' => {
+                if !(word.is_at(n as isize - 1, 'M') && is_last) {
+                    code.push('B');
+                }
+            }
+            'C' => {
+                if word.is_at(n as isize - 1, 'S') && is_next_front_vowel {
+                    // silent in "sci", "sce" and "scy"
+                } else if word.contains(n, &["CIA"]) {
+                    code.push('X');
+                } else if is_next_front_vowel {
+                    code.push('S');
+                } else if word.is_at(n as isize - 1, 'S') && word.is_at(n as isize + 1, 'H') {
+                    code.push('K');
+                } else if word.is_at(n as isize + 1, 'H') {
+                    match n == 0 && len >= 3 && word.is_any_at(2, METAPHONE_VOWELS) {
+                        true => code.push('K'),
+                        false => code.push('X'),
+                    }
+                } else {
+                    code.push('K');
+                }
+            }
+            'D' => {
+                if word.is_at(n as isize + 1, 'G') && word.is_any_at(n as isize + 2, FRONT_VOWELS) {
+                    code.push('J');
+                    n += 2;
+                } else {
+                    code.push('T');
+                }
+            }
+            'G' => {
+                let is_silent = (word.is_at(n as isize + 1, 'H')
+                    && !word.is_any_at(n as isize + 2, METAPHONE_VOWELS))
+                    || (n > 0 && word.contains(n, &["GN", "GNED"]));
+
+                if !is_silent {
+                    match is_next_front_vowel && !word.is_at(n as isize - 1, 'G') {
+                        true => code.push('J'),
+                        false => code.push('K'),
+                    }
+                }
+            }
+            'H' => {
+                let is_silent = is_last || (n > 0 && SILENT_H_AFTER.contains(letters[n - 1]));
+
+                if !is_silent && word.is_any_at(n as isize + 1, METAPHONE_VOWELS) {
+                    code.push('H');
+                }
+            }
+            'K' => {
+                if !word.is_at(n as isize - 1, 'C') {
+                    code.push('K');
+                }
+            }
+            'P' => match word.is_at(n as isize + 1, 'H') {
+                true => code.push('F'),
+                false => code.push('P'),
+            },
+            'Q' => code.push('K'),
+            'S' => match word.contains(n, &["SH", "SIO", "SIA"]) {
+                true => code.push('X'),
+                false => code.push('S'),
+            },
+            'T' => {
+                if word.contains(n, &["TIA", "TIO"]) {
+                    code.push('X');
+                } else if word.contains(n, &["TCH"]) {
+                    // silent in "tch"
+                } else if word.contains(n, &["TH"]) {
+                    code.push('0');
+                } else {
+                    code.push('T');
+                }
+            }
+            'V' => code.push('F'),
+            'W' | 'Y' => {
+                if word.is_any_at(n as isize + 1, METAPHONE_VOWELS) {
+                    code.push(letter);
+                }
+            }
+            'X' => code.push_str("KS"),
+            'Z' => code.push('S'),
+            _ => code.push(letter),
+        }
+
+        n += 1;
+    }
+
+    code.truncate(METAPHONE_LENGTH);
+
+    code
+}
+
+/// Primary and alternate codes of the Double Metaphone
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DoubleMetaphone {
+    primary: String,
+    alternate: String,
+}
+
+impl DoubleMetaphone {
+    /// Returns the primary code, the most common pronunciation
+    pub fn primary(&self) -> &str {
+        &self.primary
+    }
+
+    /// Returns the alternate code, the same as the primary one if there is no alternative
+    pub fn alternate(&self) -> &str {
+        &self.alternate
+    }
+
+    fn push(&mut self, primary: &str, alternate: &str) {
+        for (code, value) in [
+            (&mut self.primary, primary),
+            (&mut self.alternate, alternate),
+        ] {
+            let rest = METAPHONE_LENGTH.saturating_sub(code.len());
+
+            code.extend(value.chars().take(rest));
+        }
+    }
+
+    fn push_both(&mut self, value: &str) {
+        self.push(value, value);
+    }
+
+    fn is_complete(&self) -> bool {
+        self.primary.len() >= METAPHONE_LENGTH && self.alternate.len() >= METAPHONE_LENGTH
+    }
+}
+
+/// Encode the word with the Double Metaphone: the primary code and the alternate one
+/// for other pronunciations, e.g. Germanic or Slavic, codes have at most four letters
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::double_metaphone;
+///
+/// let encoded = double_metaphone("Schmidt");
+///
+/// assert_eq!(encoded.primary(), "XMT");
+/// assert_eq!(encoded.alternate(), "SMT");
+/// ```
+pub fn double_metaphone<T: AsRef<str>>(word: T) -> DoubleMetaphone {
+    let letters = word
+        .as_ref()
+        .trim()
+        .to_uppercase()
+        .chars()
+        .collect::<Vec<char>>();
+    let value = Letters(&letters);
+    let len = letters.len() as isize;
+
+    let mut result = DoubleMetaphone {
+        primary: String::new(),
+        alternate: String::new(),
+    };
+
+    if letters.is_empty() {
+        return result;
+    }
+
+    let is_slavo_germanic = letters.contains(&'W')
+        || letters.contains(&'K')
+        || (0..letters.len()).any(|i| value.contains(i, &["CZ", "WITZ"]));
+
+    let mut index: isize = match value.contains(0, &SILENT_STARTS) {
+        true => 1,
+        false => 0,
+    };
+
+    while !result.is_complete() && index < len {
+        let next = value.at(index + 1);
+
+        index = match value.at(index) {
+            'A' | 'E' | 'I' | 'O' | 'U' | 'Y' => {
+                if index == 0 {
+                    result.push_both("A");
+                }
+
+                index + 1
+            }
+            'B' => {
+                result.push_both("P");
+
+                skip_double(index, next == 'B')
+            }
+            'Ç' => {
+                result.push_both("S");
+
+                index + 1
+            }
+            'C' => double_metaphone_c(&value, &mut result, index),
+            'D' => {
+                if value.contains_at(index, &["DG"]) {
+                    if value.contains_at(index + 2, &["I", "E", "Y"]) {
+                        result.push_both("J");
+
+                        index + 3
+                    } else {
+                        result.push_both("TK");
+
+                        index + 2
+                    }
+                } else if value.contains_at(index, &["DT", "DD"]) {
+                    result.push_both("T");
+
+                    index + 2
+                } else {
+                    result.push_both("T");
+
+                    index + 1
+                }
+            }
+            'F' => {
+                result.push_both("F");
+
+                skip_double(index, next == 'F')
+            }
+            'G' => double_metaphone_g(&value, &mut result, index, is_slavo_germanic),
+            'H' => {
+                // kept at the start or between vowels
+                if (index == 0 || value.is_vowel(index - 1)) && value.is_vowel(index + 1) {
+                    result.push_both("H");
+
+                    index + 2
+                } else {
+                    index + 1
+                }
+            }
+            'J' => double_metaphone_j(&value, &mut result, index, is_slavo_germanic),
+            'K' => {
+                result.push_both("K");
+
+                skip_double(index, next == 'K')
+            }
+            'L' => {
+                if next == 'L' {
+                    let is_spanish = (index == len - 3
+                        && value.contains_at(index - 1, &["ILLO", "ILLA", "ALLE"]))
+                        || ((value.contains_at(len - 2, &["AS", "OS"])
+                            || value.contains_at(len - 1, &["A", "O"]))
+                            && value.contains_at(index - 1, &["ALLE"]));
+
+                    match is_spanish {
+                        true => result.push("L", ""),
+                        false => result.push_both("L"),
+                    }
+
+                    index + 2
+                } else {
+                    result.push_both("L");
+
+                    index + 1
+                }
+            }
+            'M' => {
+                result.push_both("M");
+
+                let is_silent_b = value.contains_at(index - 1, &["UMB"])
+                    && (index + 1 == len - 1 || value.contains_at(index + 2, &["ER"]));
+
+                skip_double(index, next == 'M' || is_silent_b)
+            }
+            'N' => {
+                result.push_both("N");
+
+                skip_double(index, next == 'N')
+            }
+            'Ñ' => {
+                result.push_both("N");
+
+                index + 1
+            }
+            'P' => {
+                if next == 'H' {
+                    result.push_both("F");
+
+                    index + 2
+                } else {
+                    result.push_both("P");
+
+                    skip_double(index, value.contains_at(index + 1, &["P", "B"]))
+                }
+            }
+            'Q' => {
+                result.push_both("K");
+
+                skip_double(index, next == 'Q')
+            }
+            'R' => {
+                // French final r, e.g. "Rogier"
+                if index == len - 1
+                    && !is_slavo_germanic
+                    && value.contains_at(index - 2, &["IE"])
+                    && !value.contains_at(index - 4, &["ME", "MA"])
+                {
+                    result.push("", "R");
+                } else {
+                    result.push_both("R");
+                }
+
+                skip_double(index, next == 'R')
+            }
+            'S' => double_metaphone_s(&value, &mut result, index, is_slavo_germanic),
+            'T' => {
+                if value.contains_at(index, &["TION", "TIA", "TCH"]) {
+                    result.push_both("X");
+
+                    index + 3
+                } else if value.contains_at(index, &["TH", "TTH"]) {
+                    if value.contains_at(index + 2, &["OM", "AM"]) || value.is_germanic() {
+                        result.push_both("T");
+                    } else {
+                        result.push("0", "T");
+                    }
+
+                    index + 2
+                } else {
+                    result.push_both("T");
+
+                    skip_double(index, value.contains_at(index + 1, &["T", "D"]))
+                }
+            }
+            'V' => {
+                result.push_both("F");
+
+                skip_double(index, next == 'V')
+            }
+            'W' => double_metaphone_w(&value, &mut result, index),
+            'X' => {
+                if index == 0 {
+                    result.push_both("S");
+
+                    index + 1
+                } else {
+                    // French final x, e.g. "breaux"
+                    let is_silent = index == len - 1
+                        && (value.contains_at(index - 3, &["IAU", "EAU"])
+                            || value.contains_at(index - 2, &["AU", "OU"]));
+
+                    if !is_silent {
+                        result.push_both("KS");
+                    }
+
+                    skip_double(index, value.contains_at(index + 1, &["C", "X"]))
+                }
+            }
+            'Z' => {
+                if next == 'H' {
+                    result.push_both("J");
+
+                    index + 2
+                } else {
+                    if value.contains_at(index + 1, &["ZO", "ZI", "ZA"])
+                        || (is_slavo_germanic && index > 0 && value.at(index - 1) != 'T')
+                    {
+                        result.push("S", "TS");
+                    } else {
+                        result.push_both("S");
+                    }
+
+                    skip_double(index, next == 'Z')
+                }
+            }
+            _ => index + 1,
+        };
+    }
+
+    result
+}
+
+fn skip_double(index: isize, is_double: bool) -> isize {
+    match is_double {
+        true => index + 2,
+        false => index + 1,
+    }
+}
+
+fn double_metaphone_c(value: &Letters, result: &mut DoubleMetaphone, index: isize) -> isize {
+    // Germanic "ach", e.g. "Bacher", but not "macher"
+    let is_germanic_ach = value.contains_at(index, &["CHIA"])
+        || (index > 1
+            && !value.is_vowel(index - 2)
+            && value.contains_at(index - 1, &["ACH"])
+            && (!matches!(value.at(index + 2), 'I' | 'E')
+                || value.contains_at(index - 2, &["BACHER", "MACHER"])));
+
+    if is_germanic_ach {
+        result.push_both("K");
+
+        index + 2
+    } else if index == 0 && value.contains_at(index, &["CAESAR"]) {
+        result.push_both("S");
+
+        index + 2
+    } else if value.contains_at(index, &["CH"]) {
+        double_metaphone_ch(value, result, index)
+    } else if value.contains_at(index, &["CZ"]) && !value.contains_at(index - 2, &["WICZ"]) {
+        // "Czerny"
+        result.push("S", "X");
+
+        index + 2
+    } else if value.contains_at(index + 1, &["CIA"]) {
+        // "focaccia"
+        result.push_both("X");
+
+        index + 3
+    } else if value.contains_at(index, &["CC"]) && !(index == 1 && value.at(0) == 'M') {
+        // double "cc" but not "McClelland"
+        if value.contains_at(index + 2, &["I", "E", "H"]) && !value.contains_at(index + 2, &["HU"])
+        {
+            // "accident", "succeed" and Italian "bacci"
+            if (index == 1 && value.at(index - 1) == 'A')
+                || value.contains_at(index - 1, &["UCCEE", "UCCES"])
+            {
+                result.push_both("KS");
+            } else {
+                result.push_both("X");
+            }
+
+            index + 3
+        } else {
+            result.push_both("K");
+
+            index + 2
+        }
+    } else if value.contains_at(index, &["CK", "CG", "CQ"]) {
+        result.push_both("K");
+
+        index + 2
+    } else if value.contains_at(index, &["CI", "CE", "CY"]) {
+        // Italian vs English
+        match value.contains_at(index, &["CIO", "CIE", "CIA"]) {
+            true => result.push("S", "X"),
+            false => result.push_both("S"),
+        }
+
+        index + 2
+    } else {
+        result.push_both("K");
+
+        if value.contains_at(index + 1, &[" C", " Q", " G"]) {
+            // "Mac Caffrey", "Mac Gregor"
+            index + 3
+        } else if value.contains_at(index + 1, &["C", "K", "Q"])
+            && !value.contains_at(index + 1, &["CE", "CI"])
+        {
+            index + 2
+        } else {
+            index + 1
+        }
+    }
+}
+
+fn double_metaphone_ch(value: &Letters, result: &mut DoubleMetaphone, index: isize) -> isize {
+    // Greek roots, e.g. "chemistry", "chorus"
+    let is_greek = index == 0
+        && (value.contains_at(index + 1, &["HARAC", "HARIS"])
+            || value.contains_at(index + 1, &["HOR", "HYM", "HIA", "HEM"]))
+        && !value.contains_at(0, &["CHORE"]);
+
+    // Germanic or Greek "kh" sound
+    let is_kh = value.is_germanic()
+        || value.contains_at(index - 2, &["ORCHES", "ARCHIT", "ORCHID"])
+        || value.contains_at(index + 2, &["T", "S"])
+        || ((value.contains_at(index - 1, &["A", "O", "U", "E"]) || index == 0)
+            && (value.contains_at(
+                index + 2,
+                &["L", "R", "N", "M", "B", "H", "F", "V", "W", " "],
+            ) || index + 1 == value.len() - 1));
+
+    if index > 0 && value.contains_at(index, &["CHAE"]) {
+        // "Michael"
+        result.push("K", "X");
+    } else if is_greek || is_kh {
+        result.push_both("K");
+    } else if index == 0 {
+        result.push_both("X");
+    } else if value.contains_at(0, &["MC"]) {
+        result.push_both("K");
+    } else {
+        result.push("X", "K");
+    }
+
+    index + 2
+}
+
+fn double_metaphone_g(
+    value: &Letters,
+    result: &mut DoubleMetaphone,
+    index: isize,
+    is_slavo_germanic: bool,
+) -> isize {
+    let next = value.at(index + 1);
+
+    if next == 'H' {
+        return double_metaphone_gh(value, result, index);
+    }
+
+    if next == 'N' {
+        if index == 1 && value.is_vowel(0) && !is_slavo_germanic {
+            result.push("KN", "N");
+        } else if !value.contains_at(index + 2, &["EY"]) && !is_slavo_germanic {
+            result.push("N", "KN");
+        } else {
+            result.push_both("KN");
+        }
+
+        return index + 2;
+    }
+
+    if value.contains_at(index + 1, &["LI"]) && !is_slavo_germanic {
+        result.push("KL", "L");
+
+        return index + 2;
+    }
+
+    let is_initial_soft = index == 0
+        && (next == 'Y'
+            || value.contains_at(
+                index + 1,
+                &[
+                    "ES", "EP", "EB", "EL", "EY", "IB", "IL", "IN", "IE", "EI", "ER",
+                ],
+            ));
+
+    // -ger-, -gy-
+    let is_ger = (value.contains_at(index + 1, &["ER"]) || next == 'Y')
+        && !value.contains_at(0, &["DANGER", "RANGER", "MANGER"])
+        && !value.contains_at(index - 1, &["E", "I"])
+        && !value.contains_at(index - 1, &["RGY", "OGY"]);
+
+    if is_initial_soft || is_ger {
+        result.push("K", "J");
+
+        index + 2
+    } else if value.contains_at(index + 1, &["E", "I", "Y"])
+        || value.contains_at(index - 1, &["AGGI", "OGGI"])
+    {
+        // Italian "biaggi"
+        if value.is_germanic() || value.contains_at(index + 1, &["ET"]) {
+            result.push_both("K");
+        } else if value.contains_at(index + 1, &["IER"]) {
+            result.push_both("J");
+        } else {
+            result.push("J", "K");
+        }
+
+        index + 2
+    } else {
+        result.push_both("K");
+
+        skip_double(index, next == 'G')
+    }
+}
+
+fn double_metaphone_gh(value: &Letters, result: &mut DoubleMetaphone, index: isize) -> isize {
+    if index > 0 && !value.is_vowel(index - 1) {
+        result.push_both("K");
+    } else if index == 0 {
+        match value.at(index + 2) == 'I' {
+            true => result.push_both("J"),
+            false => result.push_both("K"),
+        }
+    } else if (index > 1 && value.contains_at(index - 2, &["B", "H", "D"]))
+        || (index > 2 && value.contains_at(index - 3, &["B", "H", "D"]))
+        || (index > 3 && value.contains_at(index - 4, &["B", "H"]))
+    {
+        // Parker's rule, e.g. "hugh"
+    } else if index > 2
+        && value.at(index - 1) == 'U'
+        && value.contains_at(index - 3, &["C", "G", "L", "R", "T"])
+    {
+        // "laugh", "cough", "rough"
+        result.push_both("F");
+    } else if value.at(index - 1) != 'I' {
+        result.push_both("K");
+    }
+
+    index + 2
+}
+
+fn double_metaphone_j(
+    value: &Letters,
+    result: &mut DoubleMetaphone,
+    index: isize,
+    is_slavo_germanic: bool,
+) -> isize {
+    if value.contains_at(index, &["JOSE"]) || value.contains_at(0, &["SAN "]) {
+        // Spanish "Jose", "San Jacinto"
+        if (index == 0 && value.at(index + 4) == ' ')
+            || value.len() == 4
+            || value.contains_at(0, &["SAN "])
+        {
+            result.push_both("H");
+        } else {
+            result.push("J", "H");
+        }
+
+        return index + 1;
+    }
+
+    if index == 0 {
+        result.push("J", "A");
+    } else if value.is_vowel(index - 1)
+        && !is_slavo_germanic
+        && matches!(value.at(index + 1), 'A' | 'O')
+    {
+        result.push("J", "H");
+    } else if index == value.len() - 1 {
+        result.push("J", "");
+    } else if !value.contains_at(index + 1, &["L", "T", "K", "S", "N", "M", "B", "Z"])
+        && !value.contains_at(index - 1, &["S", "K", "L"])
+    {
+        result.push_both("J");
+    }
+
+    skip_double(index, value.at(index + 1) == 'J')
+}
+
+fn double_metaphone_s(
+    value: &Letters,
+    result: &mut DoubleMetaphone,
+    index: isize,
+    is_slavo_germanic: bool,
+) -> isize {
+    if value.contains_at(index - 1, &["ISL", "YSL"]) {
+        // "island", "carlisle"
+        index + 1
+    } else if index == 0 && value.contains_at(index, &["SUGAR"]) {
+        result.push("X", "S");
+
+        index + 1
+    } else if value.contains_at(index, &["SH"]) {
+        // Germanic "Holmsheim"
+        match value.contains_at(index + 1, &["HEIM", "HOEK", "HOLM", "HOLZ"]) {
+            true => result.push_both("S"),
+            false => result.push_both("X"),
+        }
+
+        index + 2
+    } else if value.contains_at(index, &["SIO", "SIA", "SIAN"]) {
+        // Italian and Armenian
+        match is_slavo_germanic {
+            true => result.push_both("S"),
+            false => result.push("S", "X"),
+        }
+
+        index + 3
+    } else if (index == 0 && value.contains_at(index + 1, &["M", "N", "L", "W"]))
+        || value.contains_at(index + 1, &["Z"])
+    {
+        // "Smith" matches "Schmidt", "Snider" matches "Schneider"
+        result.push("S", "X");
+
+        skip_double(index, value.contains_at(index + 1, &["Z"]))
+    } else if value.contains_at(index, &["SC"]) {
+        if value.at(index + 2) == 'H' {
+            // Schlesinger's rule: Dutch "school" and "schenker"
+            if value.contains_at(index + 3, &["OO", "ER", "EN", "UY", "ED", "EM"]) {
+                match value.contains_at(index + 3, &["ER", "EN"]) {
+                    true => result.push("X", "SK"),
+                    false => result.push_both("SK"),
+                }
+            } else if index == 0 && !value.is_vowel(3) && value.at(3) != 'W' {
+                result.push("X", "S");
+            } else {
+                result.push_both("X");
+            }
+        } else if value.contains_at(index + 2, &["I", "E", "Y"]) {
+            result.push_both("S");
+        } else {
+            result.push_both("SK");
+        }
+
+        index + 3
+    } else {
+        // French final s, e.g. "Artois"
+        if index == value.len() - 1 && value.contains_at(index - 2, &["AI", "OI"]) {
+            result.push("", "S");
+        } else {
+            result.push_both("S");
+        }
+
+        skip_double(index, value.contains_at(index + 1, &["S", "Z"]))
+    }
+}
+
+fn double_metaphone_w(value: &Letters, result: &mut DoubleMetaphone, index: isize) -> isize {
+    if value.contains_at(index, &["WR"]) {
+        result.push_both("R");
+
+        index + 2
+    } else if index == 0 && (value.is_vowel(index + 1) || value.contains_at(index, &["WH"])) {
+        // "Wasserman" matches "Vasserman"
+        match value.is_vowel(index + 1) {
+            true => result.push("A", "F"),
+            false => result.push_both("A"),
+        }
+
+        index + 1
+    } else if (index == value.len() - 1 && value.is_vowel(index - 1))
+        || value.contains_at(index - 1, &["EWSKI", "EWSKY", "OWSKI", "OWSKY"])
+        || value.contains_at(0, &["SCH"])
+    {
+        // "Arnow" matches "Arnoff"
+        result.push("", "F");
+
+        index + 1
+    } else if value.contains_at(index, &["WICZ", "WITZ"]) {
+        // Polish "Filipowicz"
+        result.push("TS", "FX");
+
+        index + 4
+    } else {
+        index + 1
+    }
+}
+
+/// Uppercase letters of the word with lookups out of the bounds
+struct Letters<'a>(&'a [char]);
+
+impl Letters<'_> {
+    fn len(&self) -> isize {
+        self.0.len() as isize
+    }
+
+    /// Returns the letter at the index, `\0` out of the bounds
+    fn at(&self, index: isize) -> char {
+        match index {
+            index if index < 0 => '\0',
+            index => self.0.get(index as usize).copied().unwrap_or('\0'),
+        }
+    }
+
+    fn is_at(&self, index: isize, letter: char) -> bool {
+        self.at(index) == letter
+    }
+
+    fn is_any_at(&self, index: isize, letters: &str) -> bool {
+        letters.contains(self.at(index))
+    }
+
+    fn is_vowel(&self, index: isize) -> bool {
+        self.is_any_at(index, DOUBLE_METAPHONE_VOWELS)
+    }
+
+    /// Returns true if one of the candidates starts at the index
+    fn contains(&self, index: usize, candidates: &[&str]) -> bool {
+        self.contains_at(index as isize, candidates)
+    }
+
+    /// Returns true if one of the candidates starts at the index, false out of the bounds
+    fn contains_at(&self, index: isize, candidates: &[&str]) -> bool {
+        candidates.iter().any(|candidate| {
+            index >= 0
+                && candidate
+                    .chars()
+                    .enumerate()
+                    .all(|(offset, c)| self.0.get(index as usize + offset) == Some(&c))
+        })
+    }
+
+    /// Returns true if the word is obviously Germanic, e.g. "Van Gogh" or "Schmidt"
+    fn is_germanic(&self) -> bool {
+        self.contains_at(0, &["VAN ", "VON ", "SCH"])
+    }
+}
+
+#[cfg(test)]
+mod soundex {
+    use super::soundex;
+
+    #[test]
+    fn it_should_encode_reference_names() {
+        let names = [
+            ("Robert", "R163"),
+            ("Rupert", "R163"),
+            ("Rubin", "R150"),
+            ("Ashcraft", "A261"),
+            ("Ashcroft", "A261"),
+            ("Tymczak", "T522"),
+            ("Pfister", "P236"),
+            ("Honeyman", "H555"),
+            ("Lee", "L000"),
+            ("Gutierrez", "G362"),
+            ("Jackson", "J250"),
+        ];
+
+        for (name, code) in names {
+            assert_eq!(soundex(name), code, "{}", name);
+        }
+    }
+
+    #[test]
+    fn it_should_skip_non_letters() {
+        assert_eq!(soundex(""), "");
+        assert_eq!(soundex("O'Hara"), "O600");
+        assert_eq!(soundex("123"), "");
+    }
+}
+
+#[cfg(test)]
+mod metaphone {
+    use super::metaphone;
+
+    #[test]
+    fn it_should_encode_reference_words() {
+        let words = [
+            ("howl", "HL"),
+            ("testing", "TSTN"),
+            ("The", "0"),
+            ("quick", "KK"),
+            ("brown", "BRN"),
+            ("fox", "FKS"),
+            ("jumped", "JMPT"),
+            ("over", "OFR"),
+            ("lazy", "LS"),
+            ("dogs", "TKS"),
+        ];
+
+        for (word, code) in words {
+            assert_eq!(metaphone(word), code, "{}", word);
+        }
+    }
+
+    #[test]
+    fn it_should_encode_initial_letters() {
+        assert_eq!(metaphone("knight"), "NT");
+        assert_eq!(metaphone("wright"), "RT");
+        assert_eq!(metaphone("white"), "WT");
+        assert_eq!(metaphone("Xavier"), "SFR");
+        assert_eq!(metaphone("a"), "A");
+        assert_eq!(metaphone(""), "");
+    }
+}
+
+#[cfg(test)]
+mod double_metaphone {
+    use super::double_metaphone;
+
+    fn codes(word: &str) -> (String, String) {
+        let encoded = double_metaphone(word);
+
+        (
+            encoded.primary().to_string(),
+            encoded.alternate().to_string(),
+        )
+    }
+
+    #[test]
+    fn it_should_encode_reference_words() {
+        let words = [
+            ("testing", "TSTN", "TSTN"),
+            ("The", "0", "T"),
+            ("quick", "KK", "KK"),
+            ("brown", "PRN", "PRN"),
+            ("fox", "FKS", "FKS"),
+            ("jumped", "JMPT", "AMPT"),
+            ("over", "AFR", "AFR"),
+            ("lazy", "LS", "LS"),
+            ("dogs", "TKS", "TKS"),
+        ];
+
+        for (word, primary, alternate) in words {
+            assert_eq!(
+                codes(word),
+                (primary.to_string(), alternate.to_string()),
+                "{}",
+                word
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_encode_names() {
+        let names = [
+            ("Smith", "SM0", "XMT"),
+            ("Schmidt", "XMT", "SMT"),
+            ("Thompson", "TMPS", "TMPS"),
+            ("Jose", "HS", "HS"),
+            ("Xavier", "SF", "SFR"),
+            ("Caesar", "SSR", "SSR"),
+            ("Arnow", "ARN", "ARNF"),
+            ("Michael", "MKL", "MXL"),
+            ("Knight", "NT", "NT"),
+            ("laugh", "LF", "LF"),
+            ("Dumb", "TM", "TM"),
+        ];
+
+        for (name, primary, alternate) in names {
+            assert_eq!(
+                codes(name),
+                (primary.to_string(), alternate.to_string()),
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_encode_empty() {
+        assert_eq!(codes(""), (String::new(), String::new()));
+    }
+}
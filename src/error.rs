@@ -0,0 +1,38 @@
+use std::fmt;
+
+/// Errors of the library
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// Unknown name of the sound kind
+    UnknownSoundKind(String),
+    /// Invalid entry of the lexicon
+    InvalidEntry { word: String, message: String },
+    /// Invalid line of the text source
+    Syntax { line: usize, message: String },
+    /// Unknown name of the bundled profile
+    UnknownProfile(String),
+    /// Invalid contrast of the minimal pairs, e.g. "Th" without the second sound
+    InvalidContrast(String),
+    /// No words of the word list have the target sounds of the drill
+    NoDrillWords,
+    /// Failed reading of the source file
+    Io(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::UnknownSoundKind(name) => write!(f, "unknown sound kind '{}'", name),
+            Error::InvalidEntry { word, message } => {
+                write!(f, "invalid entry '{}': {}", word, message)
+            }
+            Error::Syntax { line, message } => write!(f, "line {}: {}", line, message),
+            Error::UnknownProfile(name) => write!(f, "unknown profile '{}'", name),
+            Error::InvalidContrast(contrast) => write!(f, "invalid contrast '{}'", contrast),
+            Error::NoDrillWords => write!(f, "no words with the target sounds of the drill"),
+            Error::Io(message) => write!(f, "io error: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
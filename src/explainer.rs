@@ -0,0 +1,157 @@
+use crate::options::Options;
+use crate::parser::parse_words_with;
+use crate::sound::SoundKind;
+use std::fmt;
+use std::ops::Range;
+
+/// Explanation of the sound: its text, byte span in the source text, kind and the rule
+#[derive(Debug, Clone, PartialEq)]
+pub struct Explanation {
+    text: String,
+    span: Range<usize>,
+    kind: SoundKind,
+    rule: String,
+}
+
+impl Explanation {
+    /// Returns text of the sound
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns byte span of the sound in the source text,
+    /// the span of the whole word if the word is expanded or spoken not as written
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Returns kind of the sound
+    pub fn kind(&self) -> SoundKind {
+        self.kind
+    }
+
+    /// Returns the rule that produced the sound
+    pub fn rule(&self) -> &str {
+        &self.rule
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}..{}) {:?}: {}",
+            self.text, self.span.start, self.span.end, self.kind, self.rule
+        )
+    }
+}
+
+/// Explain sounds of the text: the matched rules per sound
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::{explain, SoundKind};
+///
+/// let explanations = explain("Put it");
+///
+/// assert_eq!(explanations.len(), 3);
+/// assert_eq!(explanations[0].kind(), SoundKind::Ptk);
+/// assert_eq!(explanations[0].rule(), "word-initial p, t or c → aspirated Ptk");
+/// assert_eq!(explanations[2].to_string(), "t (5..6) Ptk: word-final p, t or c → Ptk");
+/// ```
+pub fn explain<T: AsRef<str>>(text: T) -> Vec<Explanation> {
+    explain_with(text, &Options::default())
+}
+
+/// Explain sounds of the text with the options
+pub fn explain_with<T: AsRef<str>>(text: T, options: &Options) -> Vec<Explanation> {
+    let mut explanations = vec![];
+
+    for word in parse_words_with(text, options) {
+        for (sound, span) in word.sounds().iter().zip(word.sound_spans()) {
+            if let Some(rule) = sound.rule() {
+                explanations.push(Explanation {
+                    text: sound.text().to_string(),
+                    span,
+                    kind: *sound.kind(),
+                    rule: rule.to_string(),
+                });
+            }
+        }
+    }
+
+    explanations
+}
+
+#[cfg(test)]
+mod explain {
+    use super::{explain, explain_with, Options, SoundKind};
+    use crate::lexicon::Lexicon;
+
+    #[test]
+    fn it_should_explain_empty() {
+        assert!(explain("").is_empty());
+    }
+
+    #[test]
+    fn it_should_explain_rules() {
+        let explanations = explain("the cling");
+
+        let rules = explanations
+            .iter()
+            .map(|explanation| (explanation.text(), explanation.span(), explanation.rule()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            rules,
+            vec![
+                ("th", 0..2, "th → Th"),
+                ("c", 4..5, "word-initial p, t or c → aspirated Ptk"),
+                ("ng", 7..9, "n before g or k → Ng"),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_explain_lexicon_and_initialisms() {
+        let options = Options {
+            lexicon: "thames = th/Ptk a m e s".parse::<Lexicon>().unwrap(),
+            ..Options::default()
+        };
+
+        let explanations = explain_with("Thames TV", &options);
+
+        assert_eq!(explanations.len(), 3);
+        assert_eq!(explanations[0].rule(), "lexicon: thames");
+        assert_eq!(explanations[1].rule(), "initialism: letter name of T");
+        assert_eq!(explanations[2].kind(), SoundKind::V);
+        assert_eq!(explanations[2].span(), 8..9);
+    }
+
+    #[test]
+    fn it_should_explain_expanded_words_by_source_span() {
+        let options = Options {
+            expand: true,
+            ..Options::default()
+        };
+
+        let explanations = explain_with("at 3:00", &options);
+
+        assert_eq!(explanations[0].span(), 1..2);
+        assert_eq!(explanations[1].text(), "th");
+        assert_eq!(explanations[1].span(), 3..7);
+    }
+
+    #[test]
+    fn it_should_explain_nfc_words_by_source_span() {
+        let options = Options {
+            nfc: true,
+            ..Options::default()
+        };
+        let explanations = explain_with("ca\u{301}t", &options);
+
+        assert_eq!(explanations[1].text(), "t");
+        assert_eq!(explanations[1].span(), 4..5);
+    }
+}
@@ -0,0 +1,472 @@
+use crate::error::Error;
+use crate::options::Options;
+use crate::parser::parse_words_with;
+use crate::scorer::score_word;
+use crate::sound::SoundKind;
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+/// Weight of the word that starts with a target sound, so the drill sounds like a tongue twister
+const INITIAL_WEIGHT: u64 = 1;
+
+/// Format of the drill items
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DrillFormat {
+    /// Sentences of the words, e.g. "Three thin thieves think."
+    #[default]
+    Sentences,
+    /// Comma-separated lists of the words, e.g. "three, thin, thieves, think"
+    WordList,
+}
+
+/// Difficulty level of the drill words: a third of the word list sorted by the difficulty score
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    /// The easiest third of the words
+    Easy,
+    /// The middle third of the words
+    Medium,
+    /// The hardest third of the words
+    Hard,
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    /// Parses the level from its name in any case, e.g. "easy"
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name.to_lowercase().as_str() {
+            "easy" => Ok(Level::Easy),
+            "medium" => Ok(Level::Medium),
+            "hard" => Ok(Level::Hard),
+            _ => Err(format!("unknown level '{}'", name)),
+        }
+    }
+}
+
+/// Options of the drill
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::{DrillFormat, DrillOptions, SoundKind};
+///
+/// let drill_options = DrillOptions {
+///     kinds: vec![SoundKind::Th],
+///     format: DrillFormat::WordList,
+///     seed: 42,
+///     ..DrillOptions::default()
+/// };
+///
+/// assert_eq!(drill_options.items, 5);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DrillOptions {
+    /// Target sounds of the drill, words without them are skipped
+    pub kinds: Vec<SoundKind>,
+    /// Number of the items: sentences or word lists
+    pub items: usize,
+    /// Number of the words of every item
+    pub length: usize,
+    /// Difficulty level of the words, all the words by default
+    pub level: Option<Level>,
+    /// Format of the items
+    pub format: DrillFormat,
+    /// Seed of the random generator, the same seed gives the same drill
+    pub seed: u64,
+}
+
+impl Default for DrillOptions {
+    fn default() -> Self {
+        Self {
+            kinds: vec![],
+            items: 5,
+            length: 6,
+            level: None,
+            format: DrillFormat::default(),
+            seed: 0,
+        }
+    }
+}
+
+/// Practice text with a high density of the target sounds
+#[derive(Debug, Clone, PartialEq)]
+pub struct Drill {
+    items: Vec<String>,
+    density: f64,
+}
+
+impl Drill {
+    /// Returns the sentences or the word lists
+    pub fn items(&self) -> &[String] {
+        &self.items
+    }
+
+    /// Returns share of the target sounds among all the sounds of the drill, from 0 to 1
+    pub fn density(&self) -> f64 {
+        self.density
+    }
+}
+
+impl fmt::Display for Drill {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.items.join("\n"))
+    }
+}
+
+/// Word of the list with the target sounds
+struct Candidate {
+    text: String,
+    sounds: usize,
+    targets: usize,
+    weight: u64,
+    score: f64,
+}
+
+/// Generate practice sentences or word lists with a high density of the target sounds from the word list
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::{drill, DrillOptions, SoundKind};
+///
+/// let words = ["three", "thin", "thieves", "think", "cat", "thought", "dog"];
+/// let drill_options = DrillOptions {
+///     kinds: vec![SoundKind::Th],
+///     items: 2,
+///     length: 4,
+///     ..DrillOptions::default()
+/// };
+///
+/// let drill = drill(words, &drill_options).unwrap();
+///
+/// assert_eq!(drill.items().len(), 2);
+/// assert!(!drill.to_string().contains("cat"));
+/// assert_eq!(drill, text_to_sounds::drill(words, &drill_options).unwrap());
+/// ```
+pub fn drill<I>(words: I, drill_options: &DrillOptions) -> Result<Drill, Error>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    drill_with(words, drill_options, &Options::default())
+}
+
+/// Generate the drill from the word list with the options
+pub fn drill_with<I>(
+    words: I,
+    drill_options: &DrillOptions,
+    options: &Options,
+) -> Result<Drill, Error>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut candidates: Vec<Candidate> = vec![];
+    let mut seen: HashSet<String> = HashSet::new();
+
+    for word in words {
+        let text = word.as_ref().trim();
+
+        if text.is_empty() || !seen.insert(text.to_lowercase()) {
+            continue;
+        }
+
+        let parsed = parse_words_with(text, options)
+            .into_iter()
+            .filter(|word| !word.is_separator())
+            .collect::<Vec<_>>();
+
+        let sounds = parsed
+            .iter()
+            .flat_map(|word| word.sounds())
+            .collect::<Vec<_>>();
+        let targets = sounds
+            .iter()
+            .filter(|sound| drill_options.kinds.contains(sound.kind()))
+            .count();
+
+        if targets == 0 {
+            continue;
+        }
+
+        let initial = match sounds.first() {
+            Some(sound) if drill_options.kinds.contains(sound.kind()) => INITIAL_WEIGHT,
+            _ => 0,
+        };
+
+        candidates.push(Candidate {
+            text: text.to_string(),
+            sounds: sounds.len(),
+            targets,
+            weight: targets as u64 + initial,
+            score: parsed
+                .iter()
+                .map(|word| score_word(word, options).score())
+                .sum(),
+        });
+    }
+
+    if candidates.is_empty() {
+        return Err(Error::NoDrillWords);
+    }
+
+    let candidates = match drill_options.level {
+        Some(level) => of_level(candidates, level),
+        None => candidates,
+    };
+
+    let mut random = Random(drill_options.seed);
+    let (mut sounds, mut targets) = (0, 0);
+    let mut items = vec![];
+
+    for _ in 0..drill_options.items {
+        let mut item: Vec<&str> = vec![];
+        let mut pool: Vec<&Candidate> = vec![];
+
+        for _ in 0..drill_options.length {
+            // words of the item are not repeated unless the word list is shorter than the item
+            if pool.is_empty() {
+                pool = candidates.iter().collect();
+            }
+
+            let candidate = pool.remove(pick(&pool, &mut random));
+
+            sounds += candidate.sounds;
+            targets += candidate.targets;
+            item.push(&candidate.text);
+        }
+
+        if !item.is_empty() {
+            items.push(format_item(&item, drill_options.format));
+        }
+    }
+
+    Ok(Drill {
+        items,
+        density: match sounds {
+            0 => 0.0,
+            sounds => targets as f64 / sounds as f64,
+        },
+    })
+}
+
+/// Returns the third of the candidates of the level, sorted by the difficulty score
+fn of_level(mut candidates: Vec<Candidate>, level: Level) -> Vec<Candidate> {
+    candidates.sort_by(|a, b| a.score.total_cmp(&b.score));
+
+    let len = candidates.len();
+    let third = len.div_ceil(3);
+
+    let range = match level {
+        Level::Easy => 0..third,
+        Level::Medium => len / 3..len - len / 3,
+        Level::Hard => len - third..len,
+    };
+
+    candidates.drain(range).collect()
+}
+
+/// Returns index of the candidate picked by its weight
+fn pick(pool: &[&Candidate], random: &mut Random) -> usize {
+    let total = pool.iter().map(|candidate| candidate.weight).sum::<u64>();
+    let mut point = random.below(total);
+
+    for (index, candidate) in pool.iter().enumerate() {
+        if point < candidate.weight {
+            return index;
+        }
+
+        point -= candidate.weight;
+    }
+
+    pool.len() - 1
+}
+
+fn format_item(words: &[&str], format: DrillFormat) -> String {
+    match format {
+        DrillFormat::Sentences => {
+            let sentence = words.join(" ");
+            let mut chars = sentence.chars();
+
+            match chars.next() {
+                Some(first) => format!("{}{}.", first.to_uppercase(), chars.as_str()),
+                None => sentence,
+            }
+        }
+        DrillFormat::WordList => words.join(", "),
+    }
+}
+
+/// SplitMix64 random generator, small and reproducible on every platform
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.0;
+
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+        z ^ (z >> 31)
+    }
+
+    /// Returns a random number below the bound
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+}
+
+#[cfg(test)]
+mod drill {
+    use super::{drill, drill_with, DrillFormat, DrillOptions, Error, Level, Options};
+    use crate::dialect::Dialect;
+    use crate::sound::SoundKind;
+
+    const WORDS: [&str; 12] = [
+        "three", "thin", "thieves", "think", "thought", "through", "cat", "dog", "sun", "vet",
+        "very", "wet",
+    ];
+
+    fn options(kinds: Vec<SoundKind>) -> DrillOptions {
+        DrillOptions {
+            kinds,
+            items: 3,
+            length: 4,
+            ..DrillOptions::default()
+        }
+    }
+
+    #[test]
+    fn it_should_generate_words_with_target_sounds() {
+        let drill = drill(WORDS, &options(vec![SoundKind::Th])).unwrap();
+
+        assert_eq!(drill.items().len(), 3);
+
+        for item in drill.items() {
+            assert!(item.ends_with('.'));
+            assert!(item.starts_with("Th"));
+            assert_eq!(item.split_whitespace().count(), 4);
+        }
+
+        assert!(drill.density() > 0.2);
+    }
+
+    #[test]
+    fn it_should_be_reproducible_by_seed() {
+        let drill_options = options(vec![SoundKind::V, SoundKind::W]);
+        let seeded = DrillOptions {
+            seed: 7,
+            ..drill_options.clone()
+        };
+
+        assert_eq!(
+            drill(WORDS, &drill_options).unwrap(),
+            drill(WORDS, &drill_options).unwrap()
+        );
+        assert_ne!(
+            drill(WORDS, &drill_options).unwrap().items(),
+            drill(WORDS, &seeded).unwrap().items()
+        );
+    }
+
+    #[test]
+    fn it_should_not_repeat_words_in_item() {
+        let drill = drill(WORDS, &options(vec![SoundKind::Th])).unwrap();
+
+        for item in drill.items() {
+            let mut words = item
+                .trim_end_matches('.')
+                .split_whitespace()
+                .map(str::to_lowercase)
+                .collect::<Vec<String>>();
+
+            words.sort();
+            words.dedup();
+
+            assert_eq!(words.len(), 4);
+        }
+    }
+
+    #[test]
+    fn it_should_generate_word_lists_of_level() {
+        let drill_options = DrillOptions {
+            format: DrillFormat::WordList,
+            level: Some(Level::Easy),
+            items: 1,
+            length: 2,
+            ..options(vec![SoundKind::Th])
+        };
+
+        let easy = drill(WORDS, &drill_options).unwrap();
+        let hard = drill(
+            WORDS,
+            &DrillOptions {
+                level: Some(Level::Hard),
+                ..drill_options.clone()
+            },
+        )
+        .unwrap();
+
+        assert!(easy.items()[0].contains(", "));
+        assert!(easy.items()[0].contains("thin"));
+        assert!(!easy.items()[0].contains("thought"));
+        assert!(hard.items()[0].contains("thought"));
+        assert!(hard.items()[0].contains("through"));
+    }
+
+    #[test]
+    fn it_should_skip_repeated_words() {
+        let drill = drill(
+            ["thin", "Thin", "THIN", "three"],
+            &DrillOptions {
+                items: 1,
+                length: 2,
+                ..options(vec![SoundKind::Th])
+            },
+        )
+        .unwrap();
+
+        assert!(drill.items()[0].to_lowercase().contains("three"));
+    }
+
+    #[test]
+    fn it_should_parse_level() {
+        assert_eq!("Easy".parse::<Level>(), Ok(Level::Easy));
+        assert_eq!("hard".parse::<Level>(), Ok(Level::Hard));
+        assert_eq!(
+            "tough".parse::<Level>(),
+            Err(String::from("unknown level 'tough'"))
+        );
+    }
+
+    #[test]
+    fn it_should_report_missed_words() {
+        assert_eq!(
+            drill(["cat", "dog"], &options(vec![SoundKind::Th])),
+            Err(Error::NoDrillWords)
+        );
+        assert_eq!(drill(WORDS, &options(vec![])), Err(Error::NoDrillWords));
+    }
+
+    #[test]
+    fn it_should_generate_with_options() {
+        let options = Options {
+            dialect: Dialect::ReceivedPronunciation,
+            ..Options::default()
+        };
+        let drill_options = DrillOptions {
+            kinds: vec![SoundKind::Ch],
+            ..DrillOptions::default()
+        };
+
+        assert!(drill(["tune", "dog"], &drill_options).is_err());
+        assert!(drill_with(["tune", "dog"], &drill_options, &options)
+            .unwrap()
+            .to_string()
+            .starts_with("Tune tune"));
+    }
+}
@@ -0,0 +1,332 @@
+use crate::options::Options;
+use crate::parser::{parse_paragraphs_with, parse_words_with};
+use crate::rules::{is_vowel, vowel_groups};
+use crate::sound::Sound;
+use crate::word::Word;
+use std::fmt;
+
+/// Least number of the syllables of the polysyllabic word for SMOG
+const POLYSYLLABLE: usize = 3;
+
+/// Letters before the final "es" that keep it syllabic, e.g. "boxes"
+const SYLLABIC_ES_AFTER: [char; 6] = ['s', 'z', 'x', 'c', 'g', 'h'];
+
+/// Letters before the final "ed" that keep it syllabic, e.g. "wanted"
+const SYLLABIC_ED_AFTER: [char; 2] = ['t', 'd'];
+
+/// Readability metrics of the text with the real syllable counts of its sounds
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Readability {
+    words: usize,
+    sentences: usize,
+    syllables: usize,
+    polysyllables: usize,
+    letters: usize,
+}
+
+impl Readability {
+    /// Returns number of the words
+    pub fn words(&self) -> usize {
+        self.words
+    }
+
+    /// Returns number of the sentences
+    pub fn sentences(&self) -> usize {
+        self.sentences
+    }
+
+    /// Returns number of the syllables
+    pub fn syllables(&self) -> usize {
+        self.syllables
+    }
+
+    /// Returns number of the words with three syllables or more
+    pub fn polysyllables(&self) -> usize {
+        self.polysyllables
+    }
+
+    /// Returns number of the letters and digits of the words
+    pub fn letters(&self) -> usize {
+        self.letters
+    }
+
+    /// Returns Flesch Reading Ease, from about 100 (easy) to 0 (hard)
+    pub fn flesch_reading_ease(&self) -> f64 {
+        match self.is_empty() {
+            true => 0.0,
+            false => 206.835 - 1.015 * self.words_per_sentence() - 84.6 * self.syllables_per_word(),
+        }
+    }
+
+    /// Returns Flesch-Kincaid Grade Level, the US school grade of the text
+    pub fn flesch_kincaid_grade(&self) -> f64 {
+        match self.is_empty() {
+            true => 0.0,
+            false => 0.39 * self.words_per_sentence() + 11.8 * self.syllables_per_word() - 15.59,
+        }
+    }
+
+    /// Returns SMOG grade by the polysyllabic words, meant for 30 sentences or more
+    pub fn smog(&self) -> f64 {
+        match self.is_empty() {
+            true => 0.0,
+            false => {
+                1.043 * (self.polysyllables as f64 * 30.0 / self.sentences as f64).sqrt() + 3.1291
+            }
+        }
+    }
+
+    /// Returns Coleman-Liau index by the letters instead of the syllables
+    pub fn coleman_liau(&self) -> f64 {
+        match self.is_empty() {
+            true => 0.0,
+            false => {
+                let letters = self.letters as f64 / self.words as f64 * 100.0;
+                let sentences = self.sentences as f64 / self.words as f64 * 100.0;
+
+                0.0588 * letters - 0.296 * sentences - 15.8
+            }
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.words == 0 || self.sentences == 0
+    }
+
+    fn words_per_sentence(&self) -> f64 {
+        self.words as f64 / self.sentences as f64
+    }
+
+    fn syllables_per_word(&self) -> f64 {
+        self.syllables as f64 / self.words as f64
+    }
+}
+
+impl fmt::Display for Readability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "Words: {}, sentences: {}, syllables: {}",
+            self.words, self.sentences, self.syllables
+        )?;
+
+        let metrics = [
+            ("Flesch Reading Ease", self.flesch_reading_ease()),
+            ("Flesch-Kincaid Grade", self.flesch_kincaid_grade()),
+            ("SMOG", self.smog()),
+            ("Coleman-Liau", self.coleman_liau()),
+        ];
+
+        for (name, value) in metrics {
+            writeln!(f, "{:<20} {:>7.2}", name, value)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Count syllables of the text by the vowel sounds of its words
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::syllables;
+///
+/// assert_eq!(syllables("cake"), 1);
+/// assert_eq!(syllables("readability"), 5);
+/// assert_eq!(syllables("The table"), 3);
+/// ```
+pub fn syllables<T: AsRef<str>>(text: T) -> usize {
+    parse_words_with(text, &Options::default())
+        .iter()
+        .filter(|word| !word.is_separator())
+        .map(count_syllables)
+        .sum()
+}
+
+/// Measure readability of the text: Flesch Reading Ease, Flesch-Kincaid Grade, SMOG and Coleman-Liau
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::readability;
+///
+/// let readability = readability("The cat sat on the mat. It was happy.");
+///
+/// assert_eq!(readability.words(), 9);
+/// assert_eq!(readability.sentences(), 2);
+/// assert_eq!(readability.syllables(), 10);
+/// assert!(readability.flesch_reading_ease() > 100.0);
+/// ```
+pub fn readability<T: AsRef<str>>(text: T) -> Readability {
+    readability_with(text, &Options::default())
+}
+
+/// Measure readability of the text with the options, e.g. `expand` counts syllables of "3rd" as "third"
+pub fn readability_with<T: AsRef<str>>(text: T, options: &Options) -> Readability {
+    let mut readability = Readability::default();
+
+    for paragraph in parse_paragraphs_with(text, options) {
+        for sentence in paragraph.sentences() {
+            let mut words = 0;
+
+            for word in sentence.words().iter().filter(|word| !word.is_separator()) {
+                let syllables = count_syllables(word);
+
+                words += 1;
+                readability.syllables += syllables;
+                readability.letters += word.text().chars().filter(|c| c.is_alphanumeric()).count();
+
+                if syllables >= POLYSYLLABLE {
+                    readability.polysyllables += 1;
+                }
+            }
+
+            if words > 0 {
+                readability.words += words;
+                readability.sentences += 1;
+            }
+        }
+    }
+
+    readability
+}
+
+/// Returns number of the syllables of the word, at least one,
+/// the sounds of the expanded word are split into the spoken words by spaces
+fn count_syllables(word: &Word) -> usize {
+    word.sounds()
+        .split(|sound| sound.text().trim().is_empty())
+        .filter(|sounds| !sounds.is_empty())
+        .map(|sounds| count_spoken_syllables(sounds).max(1))
+        .sum::<usize>()
+        .max(1)
+}
+
+/// Counts groups of the vowel sounds without the silent final "e", "es" and "ed"
+fn count_spoken_syllables(sounds: &[Sound]) -> usize {
+    let texts = sounds
+        .iter()
+        .map(|sound| sound.text().to_lowercase())
+        .collect::<Vec<String>>();
+
+    let groups = vowel_groups(&texts).len();
+
+    let letters = texts.concat();
+    let before = |suffix: &str| letters[..letters.len() - suffix.len()].chars().last();
+
+    let is_consonant = |c: char| c.is_alphabetic() && !is_vowel(&c.to_string());
+
+    let is_silent_ending = groups > 1
+        && match letters.as_str() {
+            // the final "le" after a consonant is syllabic, e.g. "table", but not "while"
+            letters if letters.ends_with("le") => before("le").is_some_and(|c| !is_consonant(c)),
+            letters if letters.ends_with('e') => before("e").is_some_and(is_consonant),
+            letters if letters.ends_with("es") => {
+                before("es").is_some_and(|c| is_consonant(c) && !SYLLABIC_ES_AFTER.contains(&c))
+            }
+            letters if letters.ends_with("ed") => {
+                before("ed").is_some_and(|c| is_consonant(c) && !SYLLABIC_ED_AFTER.contains(&c))
+            }
+            _ => false,
+        };
+
+    match is_silent_ending {
+        true => groups - 1,
+        false => groups,
+    }
+}
+
+#[cfg(test)]
+mod syllables {
+    use super::syllables;
+
+    #[test]
+    fn it_should_count_vowel_groups() {
+        let words = [
+            ("cat", 1),
+            ("happy", 2),
+            ("beautiful", 3),
+            ("readability", 5),
+            ("people", 2),
+            ("yes", 1),
+        ];
+
+        for (word, count) in words {
+            assert_eq!(syllables(word), count, "{}", word);
+        }
+    }
+
+    #[test]
+    fn it_should_skip_silent_endings() {
+        let words = [
+            ("make", 1),
+            ("the", 1),
+            ("table", 2),
+            ("makes", 1),
+            ("boxes", 2),
+            ("jumped", 1),
+            ("wanted", 2),
+            ("while", 1),
+        ];
+
+        for (word, count) in words {
+            assert_eq!(syllables(word), count, "{}", word);
+        }
+    }
+
+    #[test]
+    fn it_should_count_at_least_one_syllable() {
+        assert_eq!(syllables("hmm"), 1);
+        assert_eq!(syllables("42"), 1);
+        assert_eq!(syllables(""), 0);
+    }
+}
+
+#[cfg(test)]
+mod readability {
+    use super::{readability, readability_with, Options};
+
+    #[test]
+    fn it_should_count_text() {
+        let readability = readability("Readability is measured. Short words help!");
+
+        assert_eq!(readability.words(), 6);
+        assert_eq!(readability.sentences(), 2);
+        assert_eq!(readability.syllables(), 11);
+        assert_eq!(readability.polysyllables(), 1);
+        assert_eq!(readability.letters(), 35);
+    }
+
+    #[test]
+    fn it_should_measure_metrics() {
+        let readability = readability("The cat sat on the mat.");
+
+        // 6 words, 1 sentence and 6 syllables
+        assert!((readability.flesch_reading_ease() - 116.145).abs() < 1e-9);
+        assert!((readability.flesch_kincaid_grade() - -1.45).abs() < 1e-9);
+        assert!((readability.smog() - 3.1291).abs() < 1e-9);
+        assert!(
+            (readability.coleman_liau() - (0.0588 * 1700.0 / 6.0 - 0.296 * 100.0 / 6.0 - 15.8))
+                .abs()
+                < 1e-9
+        );
+    }
+
+    #[test]
+    fn it_should_measure_empty_text() {
+        assert_eq!(readability("").flesch_reading_ease(), 0.0);
+        assert_eq!(readability("...").smog(), 0.0);
+    }
+
+    #[test]
+    fn it_should_measure_with_options() {
+        let options = Options {
+            expand: true,
+            ..Options::default()
+        };
+
+        assert_eq!(readability("The 21st.").syllables(), 2);
+        assert_eq!(readability_with("The 21st.", &options).syllables(), 4);
+    }
+}
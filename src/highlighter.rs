@@ -1,16 +1,18 @@
-use crate::scanner::Scanner;
-use crate::sound::SoundKind;
-
-fn highlight_two_letters(
-    first_letter: &char,
-    second_letter: &char,
-    kind: SoundKind,
-    result_text: &mut String,
-) {
-    let text = first_letter.to_string() + &String::from(*second_letter);
-
-    result_text.push_str(&format!("<span class='{:?}'>{}</span>", kind, text));
-}
+use crate::aligner::Edit;
+use crate::assessor::{assess_words, Assessment, Mispronunciation};
+use crate::connected::Link;
+use crate::language::{English, Language};
+use crate::options::Options;
+use crate::parser::parse_words_in;
+use crate::rhymer::group_rhymes;
+use crate::sound::{Sound, SoundKind};
+use crate::word::Word;
+use std::iter::Peekable;
+use std::ops::Range;
+use std::slice::Iter;
+
+/// Class of the final consonants highlighted for the learners of the profile, e.g. Chinese speakers
+const FINAL_CONSONANT_CLASS: &str = "FinalConsonant";
 
 /// Highlight sounds in the text with html tags
 ///
@@ -22,104 +24,341 @@ fn highlight_two_letters(
 /// assert_eq!(highlight("The text just in case"), "<span class='Th'>Th</span>e <span class='Ptk'>t</span>ex<span class='Ptk'>t</span> <span class='Dj'>j</span>us<span class='Ptk'>t</span> in <span class='Ptk'>c</span>ase".to_string());
 /// ```
 pub fn highlight<T: AsRef<str>>(text: T) -> String {
+    highlight_with(text, &Options::default())
+}
+
+/// Highlight sounds in the text with html tags and the options
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::{highlight_with, Options};
+///
+/// let options = Options {
+///     nfc: true,
+///     ..Options::default()
+/// };
+///
+/// assert_eq!(highlight_with("Cafe\u{301}", &options), "<span class='Ptk'>C</span>af\u{e9}".to_string());
+/// ```
+pub fn highlight_with<T: AsRef<str>>(text: T, options: &Options) -> String {
+    highlight_in(text, &English, options)
+}
+
+/// Highlight sounds in the text of the language with html tags and the options
+pub fn highlight_in<T: AsRef<str>, L: Language>(
+    text: T,
+    language: &L,
+    options: &Options,
+) -> String {
     let text = text.as_ref();
 
     if text.is_empty() {
         return text.to_string();
     }
 
-    let mut scanner = Scanner::new(text);
-
     let mut result_text = String::new();
 
-    while !scanner.is_done() {
-        match scanner.peek() {
-            letter @ ('c' | 'C') if !scanner.is_last() && scanner.is_next_any(vec!['h', 'H']) => {
-                let next_letter = scanner.peek_next();
-
-                highlight_two_letters(letter, next_letter, SoundKind::Ch, &mut result_text);
+    let words = parse_words_in(text, language, options);
+
+    let links = match options.connected_speech {
+        true => language.connect(text, &words, options),
+        false => vec![],
+    };
+
+    let rhymes = match options.rhymes {
+        true => group_rhymes(&words),
+        false => vec![],
+    };
+
+    let assessment = options.recognized.as_ref().map(|recognized| {
+        let recognized = parse_words_in(recognized, language, options)
+            .into_iter()
+            .filter(|word| !word.is_separator())
+            .flat_map(Word::into_sounds)
+            .collect::<Vec<Sound>>();
+
+        assess_words(&words, &recognized)
+    });
+
+    let mispronunciations = assessment
+        .as_ref()
+        .map(Assessment::mispronunciations)
+        .unwrap_or_default();
+
+    let mut insertions = mispronunciations
+        .iter()
+        .filter(|mispronunciation| mispronunciation.edit() == Edit::Insertion)
+        .peekable();
+
+    let mut open_links = links.iter().peekable();
+    let mut open_link: Option<&Link> = None;
+
+    for word in &words {
+        // the rhyme span is skipped if a link crosses the word, so spans are not interleaved
+        let rhyme = rhymes
+            .iter()
+            .enumerate()
+            .find(|(_, group)| group.words().iter().any(|(_, span)| *span == word.span()))
+            .filter(|_| {
+                !links
+                    .iter()
+                    .any(|link| is_overlapped(&link.span(), &word.span()))
+            });
+
+        if let Some((index, group)) = rhyme {
+            result_text.push_str(&format!(
+                "<span class='Rhyme Rhyme{}' title='{}'>",
+                index + 1,
+                escape_attribute(group.key())
+            ));
+        }
 
-                scanner.pop();
-                scanner.pop();
-            }
-            letter @ ('p' | 'P' | 't' | 'T' | 'c' | 'C')
-                if scanner.is_first() || scanner.is_last() =>
+        if word.is_expanded() {
+            while let Some(insertion) =
+                insertions.next_if(|insertion| insertion.span().start <= word.span().start)
             {
-                if (letter == &'t' || letter == &'T')
-                    && !scanner.is_last()
-                    && scanner.is_next_any(vec!['h', 'H'])
-                {
-                    let next_letter = scanner.peek_next();
+                highlight_insertion(insertion, &mut result_text);
+            }
 
-                    highlight_two_letters(letter, next_letter, SoundKind::Th, &mut result_text);
+            highlight_expanded_word(word, options, mispronunciations, &mut result_text);
+        } else {
+            highlight_word(
+                word,
+                options,
+                &mut open_links,
+                &mut open_link,
+                mispronunciations,
+                &mut insertions,
+                &mut result_text,
+            );
+        }
 
-                    scanner.pop();
-                    scanner.pop();
+        if rhyme.is_some() {
+            result_text.push_str("</span>");
+        }
+    }
 
-                    continue;
-                }
+    for insertion in insertions {
+        highlight_insertion(insertion, &mut result_text);
+    }
 
-                result_text.push_str(&format!(
-                    "<span class='{:?}'>{}</span>",
-                    SoundKind::Ptk,
-                    letter
-                ));
+    result_text
+}
 
-                scanner.pop();
-            }
-            letter @ ('t' | 'T') if scanner.is_next_any(vec!['h', 'H']) => {
-                let next_letter = scanner.peek_next();
+/// Highlights sounds of the word, the sounds of the open link are highlighted by the link,
+/// mispronounced sounds are wrapped in spans of the error
+fn highlight_word<'a, 'b, I: Iterator<Item = &'b Mispronunciation>>(
+    word: &Word,
+    options: &Options,
+    links: &mut Peekable<Iter<'a, Link>>,
+    open_link: &mut Option<&'a Link>,
+    mispronunciations: &[Mispronunciation],
+    insertions: &mut Peekable<I>,
+    result_text: &mut String,
+) {
+    for (sound, span) in word.sounds().iter().zip(word.sound_spans()) {
+        while let Some(insertion) =
+            insertions.next_if(|insertion| insertion.span().start <= span.start)
+        {
+            highlight_insertion(insertion, result_text);
+        }
 
-                highlight_two_letters(letter, next_letter, SoundKind::Th, &mut result_text);
+        if open_link.is_none() {
+            while links
+                .next_if(|link| link.span().end <= span.start)
+                .is_some()
+            {}
 
-                scanner.pop();
-                scanner.pop();
-            }
-            letter @ ('w' | 'W') if scanner.is_first() => {
-                result_text.push_str(&format!(
-                    "<span class='{:?}'>{}</span>",
-                    SoundKind::W,
-                    letter
-                ));
-
-                scanner.pop();
-            }
-            letter @ ('v' | 'V') if scanner.is_first() => {
-                result_text.push_str(&format!(
-                    "<span class='{:?}'>{}</span>",
-                    SoundKind::V,
-                    letter
-                ));
-
-                scanner.pop();
+            *open_link = links.next_if(|link| link.span().start < span.end);
+
+            if let Some(link) = open_link {
+                highlight_link_start(link, result_text);
             }
-            letter @ ('n' | 'N')
-                if !scanner.is_last() && scanner.is_next_any(vec!['g', 'G', 'k', 'K']) =>
-            {
-                let next_letter = scanner.peek_next();
+        }
 
-                highlight_two_letters(letter, next_letter, SoundKind::Ng, &mut result_text);
+        let error = mispronunciations.iter().find(|mispronunciation| {
+            mispronunciation.edit() != Edit::Insertion && mispronunciation.span() == span
+        });
 
-                scanner.pop();
-                scanner.pop();
-            }
-            letter @ ('j' | 'J') if scanner.is_first() => {
-                result_text.push_str(&format!(
-                    "<span class='{:?}'>{}</span>",
-                    SoundKind::Dj,
-                    letter
-                ));
-
-                scanner.pop();
-            }
-            _ => {
-                let letter = scanner.pop();
-                result_text.push_str(&letter.to_string());
-            }
+        if let Some(error) = error {
+            highlight_error_start(&[error], result_text);
+        }
+
+        match open_link {
+            Some(_) => result_text.push_str(sound.text()),
+            None => highlight_sound(sound, options, result_text),
+        }
+
+        if error.is_some() {
+            result_text.push_str("</span>");
+        }
+
+        if open_link.is_some_and(|link| link.span().end <= span.end) {
+            result_text.push_str("</span>");
+
+            *open_link = None;
         }
     }
+}
 
-    result_text
+fn is_overlapped(a: &Range<usize>, b: &Range<usize>) -> bool {
+    a.start < b.end && b.start < a.end
+}
+
+/// Returns the text escaped for the html attribute value, e.g. the `title` of the span
+fn escape_attribute(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// The link across the word boundary is highlighted as a whole
+/// with the classes of its kind and its resulting sound
+fn highlight_link_start(link: &Link, result_text: &mut String) {
+    let classes = match link.sound() {
+        SoundKind::Undefined => format!("{:?}", link.kind()),
+        sound => format!("{:?} {:?}", link.kind(), sound),
+    };
+
+    result_text.push_str(&format!(
+        "<span class='{}' title='{}'>",
+        classes,
+        escape_attribute(link.description())
+    ));
+}
+
+/// The error is highlighted with the classes of `Error` and its edit, e.g. `Error Substitution`
+fn highlight_error_start(errors: &[&Mispronunciation], result_text: &mut String) {
+    let mut edits: Vec<Edit> = vec![];
+
+    for error in errors {
+        if !edits.contains(&error.edit()) {
+            edits.push(error.edit());
+        }
+    }
+
+    let classes = edits
+        .iter()
+        .map(|edit| format!("{:?}", edit))
+        .collect::<Vec<String>>()
+        .join(" ");
+
+    let titles = errors
+        .iter()
+        .map(|error| escape_attribute(&error.description()))
+        .collect::<Vec<String>>()
+        .join("; ");
+
+    result_text.push_str(&format!(
+        "<span class='Error {}' title='{}'>",
+        classes, titles
+    ));
+}
+
+/// The inserted sound is missed in the text, so its recognized text is highlighted
+fn highlight_insertion(insertion: &Mispronunciation, result_text: &mut String) {
+    highlight_error_start(&[insertion], result_text);
+
+    if let Some(sound) = insertion.realized() {
+        result_text.push_str(sound.text());
+    }
+
+    result_text.push_str("</span>");
+}
+
+fn highlight_sound(sound: &Sound, options: &Options, result_text: &mut String) {
+    match class_of(sound, options) {
+        Some(class) => {
+            result_text.push_str(&format!("<span class='{}'>{}</span>", class, sound.text()))
+        }
+        None => result_text.push_str(sound.text()),
+    }
+}
+
+/// Returns class of the highlighted sound: its kind, or `FinalConsonant` for the final consonant
+/// of another kind if the profile of the learners weights final consonants
+fn class_of(sound: &Sound, options: &Options) -> Option<String> {
+    if is_highlighted(sound.kind(), options) {
+        return Some(format!("{:?}", sound.kind()));
+    }
+
+    match &options.profile {
+        Some(profile) if sound.is_final() && profile.final_weight() > 0.0 => {
+            Some(String::from(FINAL_CONSONANT_CLASS))
+        }
+        _ => None,
+    }
+}
+
+/// Sounds are highlighted unless undefined or missed in the profile of the learners
+fn is_highlighted(kind: &SoundKind, options: &Options) -> bool {
+    match &options.profile {
+        _ if kind == &SoundKind::Undefined => false,
+        Some(profile) => profile.is_highlighted(*kind),
+        None => true,
+    }
+}
+
+/// The source text of the expanded word (e.g. "3rd") is highlighted as a whole
+/// with the classes of all the sounds of the spoken words and the errors of them
+fn highlight_expanded_word(
+    word: &Word,
+    options: &Options,
+    mispronunciations: &[Mispronunciation],
+    result_text: &mut String,
+) {
+    let errors = mispronunciations
+        .iter()
+        .filter(|mispronunciation| {
+            mispronunciation.edit() != Edit::Insertion && mispronunciation.span() == word.span()
+        })
+        .collect::<Vec<&Mispronunciation>>();
+
+    if !errors.is_empty() {
+        highlight_error_start(&errors, result_text);
+    }
+
+    highlight_expanded_word_sounds(word, options, result_text);
+
+    if !errors.is_empty() {
+        result_text.push_str("</span>");
+    }
+}
+
+fn highlight_expanded_word_sounds(word: &Word, options: &Options, result_text: &mut String) {
+    let mut classes: Vec<String> = vec![];
+
+    for class in word
+        .sounds()
+        .iter()
+        .filter_map(|sound| class_of(sound, options))
+    {
+        if !classes.contains(&class) {
+            classes.push(class);
+        }
+    }
+
+    if classes.is_empty() {
+        result_text.push_str(word.text());
+
+        return;
+    }
+
+    let classes = classes.join(" ");
+
+    result_text.push_str(&format!("<span class='{}'>{}</span>", classes, word.text()));
 }
 
 #[cfg(test)]
@@ -195,4 +434,311 @@ mod highlight {
                 .to_string()
         );
     }
+
+    #[test]
+    fn it_should_highlight_with_newlines_tabs_and_quotes() {
+        assert_eq!(
+            highlight("«what»\n\tthe (cat)"),
+            "«<span class='W'>w</span>ha<span class='Ptk'>t</span>»\n\t<span class='Th'>th</span>e (<span class='Ptk'>c</span>a<span class='Ptk'>t</span>)"
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn it_should_highlight_cjk_untouched() {
+        assert_eq!(
+            highlight("中文 put"),
+            "中文 <span class='Ptk'>p</span>u<span class='Ptk'>t</span>".to_string()
+        );
+    }
+
+    #[test]
+    fn it_should_not_split_grapheme_clusters() {
+        assert_eq!(
+            highlight("witḣ ca\u{301}t 👨\u{200d}👩\u{200d}👧"),
+            "<span class='W'>w</span>itḣ <span class='Ptk'>c</span>a\u{301}<span class='Ptk'>t</span> 👨\u{200d}👩\u{200d}👧"
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn it_should_highlight_initialisms() {
+        assert_eq!(
+            highlight("The FBI, NATO and TV"),
+            "<span class='Th'>Th</span>e FBI, NATO and <span class='Ptk'>T</span><span class='V'>V</span>"
+                .to_string()
+        );
+    }
+}
+
+#[cfg(test)]
+mod highlight_with {
+    use super::{highlight_with, Options};
+
+    #[test]
+    fn it_should_highlight_with_nfc() {
+        assert_eq!(
+            highlight_with(
+                "Vi\u{301}ctor",
+                &Options {
+                    nfc: true,
+                    ..Options::default()
+                }
+            ),
+            "<span class='V'>V</span>\u{ed}ctor".to_string()
+        );
+    }
+
+    #[test]
+    fn it_should_highlight_expanded_words() {
+        let options = Options {
+            expand: true,
+            ..Options::default()
+        };
+
+        assert_eq!(
+            highlight_with("The 3rd at 10:30 & 2", &options),
+            "<span class='Th'>Th</span>e <span class='Th'>3rd</span> a<span class='Ptk'>t</span> <span class='Ptk Th Flap'>10:30</span> & <span class='Ptk'>2</span>"
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn it_should_highlight_without_expanding() {
+        assert_eq!(
+            highlight_with("The 3rd", &Options::default()),
+            "<span class='Th'>Th</span>e 3rd".to_string()
+        );
+    }
+}
+
+#[cfg(test)]
+mod highlight_connected_speech {
+    use super::{highlight_with, Options};
+
+    fn connected() -> Options {
+        Options {
+            connected_speech: true,
+            ..Options::default()
+        }
+    }
+
+    #[test]
+    fn it_should_highlight_link_across_boundary() {
+        assert_eq!(
+            highlight_with("Did you", &connected()),
+            "Di<span class='Coalescence Dj' title='final d and y → Dj'>d y</span>ou"
+        );
+    }
+
+    #[test]
+    fn it_should_highlight_link_after_decomposed_grapheme_with_nfc() {
+        let options = Options {
+            nfc: true,
+            ..connected()
+        };
+
+        assert_eq!(
+            highlight_with("Sai\u{308}d you", &options),
+            "Sa\u{ef}<span class='Coalescence Dj' title='final d and y → Dj'>d y</span>ou"
+        );
+    }
+
+    #[test]
+    fn it_should_highlight_link_instead_of_sounds() {
+        assert_eq!(
+            highlight_with("want to go", &connected()),
+            "<span class='Reduction' title='want to → wanna'>want to</span> go"
+        );
+        assert_eq!(
+            highlight_with("last night", &connected()),
+            "las<span class='Elision' title='final t between consonants is elided'>t</span> nigh<span class='Ptk'>t</span>"
+        );
+    }
+
+    #[test]
+    fn it_should_not_highlight_links_by_default() {
+        assert_eq!(highlight_with("Did you", &Options::default()), "Did you");
+    }
+}
+
+#[cfg(test)]
+mod highlight_in {
+    use super::{highlight_in, Options};
+    use crate::language::{English, German};
+
+    #[test]
+    fn it_should_highlight_in_language() {
+        assert_eq!(
+            highlight_in("Hund", &German, &Options::default()),
+            "Hun<span class='Devoiced'>d</span>"
+        );
+        assert_eq!(highlight_in("Hund", &English, &Options::default()), "Hund");
+    }
+}
+
+#[cfg(test)]
+mod highlight_with_profile {
+    use super::{highlight_with, Options};
+    use crate::profile::Profile;
+
+    fn profile(code: &str) -> Options {
+        Options {
+            profile: Some(Profile::bundled(code).unwrap()),
+            expand: true,
+            ..Options::default()
+        }
+    }
+
+    #[test]
+    fn it_should_highlight_sounds_of_profile() {
+        assert_eq!(
+            highlight_with("Very bad", &profile("es")),
+            "<span class='V'>V</span>ery <span class='B'>b</span>ad"
+        );
+        assert_eq!(
+            highlight_with("Put the rule", &profile("ja")),
+            "Put <span class='Th'>th</span>e <span class='R'>r</span>u<span class='L'>l</span>e"
+        );
+    }
+
+    #[test]
+    fn it_should_highlight_final_consonants() {
+        assert_eq!(
+            highlight_with("Big dogs", &profile("zh")),
+            "Bi<span class='FinalConsonant'>g</span> do<span class='FinalConsonant'>g</span><span class='FinalConsonant'>s</span>"
+        );
+    }
+
+    #[test]
+    fn it_should_highlight_expanded_word_by_profile() {
+        assert_eq!(
+            highlight_with("The 3rd", &profile("es")),
+            "<span class='Th'>Th</span>e <span class='Th'>3rd</span>"
+        );
+    }
+
+    #[test]
+    fn it_should_keep_original_sounds_for_russian_speakers() {
+        assert_eq!(
+            highlight_with("The text just in case", &profile("ru")),
+            highlight_with("The text just in case", &Options::default())
+        );
+    }
+}
+
+#[cfg(test)]
+mod highlight_rhymes {
+    use super::{highlight_with, Options};
+
+    fn rhymes() -> Options {
+        Options {
+            rhymes: true,
+            ..Options::default()
+        }
+    }
+
+    #[test]
+    fn it_should_group_rhyming_words() {
+        assert_eq!(
+            highlight_with("sing a ring, make a cake", &rhymes()),
+            "<span class='Rhyme Rhyme1' title='ing'>si<span class='Ng'>ng</span></span> a <span class='Rhyme Rhyme1' title='ing'>ri<span class='Ng'>ng</span></span>, <span class='Rhyme Rhyme2' title='ake'>make</span> a <span class='Rhyme Rhyme2' title='ake'><span class='Ptk'>c</span>ake</span>"
+        );
+    }
+
+    #[test]
+    fn it_should_escape_title_of_group() {
+        assert_eq!(
+            highlight_with("cat's hat's", &rhymes()),
+            "<span class='Rhyme Rhyme1' title='at&#39;s'><span class='Ptk'>c</span>at's</span> <span class='Rhyme Rhyme1' title='at&#39;s'>hat's</span>"
+        );
+    }
+
+    #[test]
+    fn it_should_not_group_words_crossed_by_links() {
+        let options = Options {
+            connected_speech: true,
+            ..rhymes()
+        };
+
+        assert_eq!(
+            highlight_with("red you, bed", &options),
+            "re<span class='Coalescence Dj' title='final d and y → Dj'>d y</span>ou, <span class='Rhyme Rhyme1' title='ed'>bed</span>"
+        );
+    }
+
+    #[test]
+    fn it_should_not_group_by_default() {
+        assert_eq!(
+            highlight_with("sing a ring", &Options::default()),
+            "si<span class='Ng'>ng</span> a ri<span class='Ng'>ng</span>"
+        );
+    }
+}
+
+#[cfg(test)]
+mod highlight_mispronunciations {
+    use super::{highlight_with, Options};
+
+    fn recognized(text: &str) -> Options {
+        Options {
+            recognized: Some(text.to_string()),
+            ..Options::default()
+        }
+    }
+
+    #[test]
+    fn it_should_highlight_substitutions_and_deletions() {
+        assert_eq!(
+            highlight_with("thin cat", &recognized("sin ca")),
+            "<span class='Error Substitution' title='Th realized as s'><span class='Th'>th</span></span>in <span class='Ptk'>c</span>a<span class='Error Deletion' title='Ptk deleted'><span class='Ptk'>t</span></span>"
+        );
+    }
+
+    #[test]
+    fn it_should_highlight_insertions() {
+        assert_eq!(
+            highlight_with("school", &recognized("eschool")),
+            "<span class='Error Insertion' title='e inserted'>e</span>s<span class='Ch'>ch</span>ool"
+        );
+        assert_eq!(
+            highlight_with("man", &recognized("mana")),
+            "man<span class='Error Insertion' title='a inserted'>a</span>"
+        );
+    }
+
+    #[test]
+    fn it_should_escape_title_of_error() {
+        // the custom rule spells the sound with the apostrophe, e.g. "n'" of "don't"
+        let options = Options {
+            rules: Some("n' -> Undefined".parse().unwrap()),
+            ..recognized("dot")
+        };
+
+        assert_eq!(
+            highlight_with("don't", &options),
+            "do<span class='Error Deletion' title='n&#39; deleted'>n'</span>t"
+        );
+    }
+
+    #[test]
+    fn it_should_highlight_errors_of_expanded_words() {
+        let options = Options {
+            expand: true,
+            ..recognized("the sird")
+        };
+
+        assert_eq!(
+            highlight_with("the 3rd", &options),
+            "<span class='Th'>th</span>e <span class='Error Substitution' title='Th realized as s'><span class='Th'>3rd</span></span>"
+        );
+    }
+
+    #[test]
+    fn it_should_not_highlight_errors_of_same_speech() {
+        assert_eq!(
+            highlight_with("The cat", &recognized("the cat")),
+            highlight_with("The cat", &Options::default())
+        );
+    }
 }
@@ -0,0 +1,454 @@
+use crate::error::Error;
+use crate::word::Word;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+/// Bundled English homographs
+const ENGLISH_HOMOGRAPHS: &str = include_str!("../data/en.homographs");
+
+/// Comment line prefix of the homographs source
+const COMMENT_PREFIX: char = '#';
+
+/// Words after which a past form is expected, e.g. "have read"
+const PAST_MARKERS: [&str; 8] = [
+    "have", "has", "had", "was", "were", "been", "already", "being",
+];
+
+/// Words later in the sentence which point to a past form, e.g. "read it yesterday"
+const PAST_NEXT_MARKERS: [&str; 2] = ["yesterday", "ago"];
+
+/// Word later in the sentence which points to a past form before a time noun, e.g. "read it last week"
+const LAST_MARKER: &str = "last";
+
+/// Time nouns after `LAST_MARKER`, "last" alone is not a past marker, e.g. "read the last chapter"
+const TIME_NOUNS: [&str; 10] = [
+    "night", "week", "weekend", "month", "year", "time", "spring", "summer", "autumn", "winter",
+];
+
+/// Number of the words after the homograph searched for the past markers
+const PAST_NEXT_WINDOW: usize = 4;
+
+/// Characters of the separators that end the search for the past markers
+const SENTENCE_END_CHARS: [char; 6] = ['.', '!', '?', ';', '…', '。'];
+
+/// Words after which an adjective is expected, e.g. "is live"
+const ADJECTIVE_MARKERS: [&str; 10] = [
+    "is", "are", "am", "be", "was", "were", "very", "so", "too", "quite",
+];
+
+/// Words after which the base form of a verb is expected, even with the past markers later, e.g. "to read"
+const BASE_FORM_MARKERS: [&str; 17] = [
+    "to", "will", "would", "can", "could", "shall", "should", "may", "might", "must", "do", "does",
+    "did", "don't", "didn't", "let's", "please",
+];
+
+/// Words after which a verb is expected, e.g. "you read"
+const VERB_MARKERS: [&str; 7] = ["i", "you", "we", "they", "not", "never", "often"];
+
+/// Words after which a noun (or an adjective before a noun) is expected, e.g. "the wind"
+const DETERMINERS: [&str; 14] = [
+    "the", "a", "an", "this", "that", "these", "those", "my", "your", "his", "her", "our", "their",
+    "its",
+];
+
+/// Words after which a noun is expected, e.g. "of lead"
+const PREPOSITIONS: [&str; 7] = ["of", "with", "in", "on", "from", "for", "without"];
+
+/// Words before which a verb is expected, e.g. "close the door"
+const OBJECT_MARKERS: [&str; 19] = [
+    "the", "a", "an", "this", "that", "my", "your", "his", "her", "our", "their", "its", "me",
+    "him", "us", "them", "it", "up", "down",
+];
+
+/// Parts of speech of the homograph variants
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartOfSpeech {
+    Noun,
+    Verb,
+    Adjective,
+    PastTense,
+}
+
+impl FromStr for PartOfSpeech {
+    type Err = String;
+
+    /// Parses the part of speech from its name, e.g. "Noun"
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Noun" => Ok(PartOfSpeech::Noun),
+            "Verb" => Ok(PartOfSpeech::Verb),
+            "Adjective" => Ok(PartOfSpeech::Adjective),
+            "PastTense" => Ok(PartOfSpeech::PastTense),
+            _ => Err(format!("unknown part of speech '{}'", name)),
+        }
+    }
+}
+
+/// Pronunciation variant of the homograph chosen by the context
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::{parse_words, PartOfSpeech};
+///
+/// let words = parse_words("I have read it");
+/// let variant = words[4].variant().unwrap();
+///
+/// assert_eq!(variant.part_of_speech(), PartOfSpeech::PastTense);
+/// assert_eq!(variant.to_string(), "/rɛd/ PastTense (after 'have')");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Variant {
+    part_of_speech: PartOfSpeech,
+    pronunciation: String,
+    reason: String,
+}
+
+impl Variant {
+    /// Returns part of speech of the variant
+    pub fn part_of_speech(&self) -> PartOfSpeech {
+        self.part_of_speech
+    }
+
+    /// Returns pronunciation of the variant in IPA, e.g. "/rɛd/"
+    pub fn pronunciation(&self) -> &str {
+        &self.pronunciation
+    }
+
+    /// Returns the reason of the choice, e.g. "after 'to'"
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl fmt::Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {:?} ({})",
+            self.pronunciation, self.part_of_speech, self.reason
+        )
+    }
+}
+
+/// Homographs with their pronunciation variants, the first variant is the default one
+#[derive(Debug, Default)]
+struct Homographs {
+    entries: HashMap<String, Vec<(PartOfSpeech, String)>>,
+}
+
+impl Homographs {
+    /// Returns the bundled English homographs
+    fn english() -> &'static Homographs {
+        static HOMOGRAPHS: OnceLock<Homographs> = OnceLock::new();
+
+        HOMOGRAPHS.get_or_init(|| {
+            ENGLISH_HOMOGRAPHS
+                .parse()
+                .expect("bundled homographs are valid")
+        })
+    }
+}
+
+impl FromStr for Homographs {
+    type Err = Error;
+
+    /// Parses homographs from lines of the word, the part of speech and the pronunciation
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let mut homographs = Homographs::default();
+
+        for (index, line) in source.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with(COMMENT_PREFIX) {
+                continue;
+            }
+
+            let syntax_error = |message: String| Error::Syntax {
+                line: index + 1,
+                message,
+            };
+
+            let fields = line.split_whitespace().collect::<Vec<&str>>();
+
+            let [word, part_of_speech, pronunciation] = fields[..] else {
+                return Err(syntax_error(String::from(
+                    "expected the word, the part of speech and the pronunciation",
+                )));
+            };
+
+            let part_of_speech = part_of_speech
+                .parse::<PartOfSpeech>()
+                .map_err(syntax_error)?;
+
+            homographs
+                .entries
+                .entry(word.to_lowercase())
+                .or_default()
+                .push((part_of_speech, pronunciation.to_string()));
+        }
+
+        Ok(homographs)
+    }
+}
+
+/// Choose pronunciation variants of the homographs by the neighbor words
+pub fn disambiguate(words: &mut [Word]) {
+    let homographs = Homographs::english();
+
+    for index in 0..words.len() {
+        let variants = match homographs.entries.get(words[index].normalized()) {
+            Some(variants) if !words[index].is_expanded() => variants,
+            _ => continue,
+        };
+
+        let prev = index
+            .checked_sub(2)
+            .and_then(|prev| neighbor(words, prev, index - 1));
+        let next = neighbor(words, index + 2, index + 1);
+        let later = ahead(words, index);
+
+        let variant = choose(variants, prev, next, &later);
+
+        words[index].set_variant(variant);
+    }
+}
+
+/// Returns the normalized neighbor word if it is separated by spaces only
+fn neighbor(words: &[Word], index: usize, separator: usize) -> Option<&str> {
+    match (words.get(separator), words.get(index)) {
+        (Some(separator), Some(word))
+            if !word.is_separator() && separator.text().chars().all(|c| c == ' ') =>
+        {
+            Some(word.normalized())
+        }
+        _ => None,
+    }
+}
+
+/// Returns the normalized words after the word up to the end of the sentence, at most `PAST_NEXT_WINDOW`
+fn ahead(words: &[Word], index: usize) -> Vec<&str> {
+    words[index + 1..]
+        .iter()
+        .take_while(|word| !(word.is_separator() && word.text().contains(SENTENCE_END_CHARS)))
+        .filter(|word| !word.is_separator())
+        .take(PAST_NEXT_WINDOW)
+        .map(Word::normalized)
+        .collect()
+}
+
+/// Returns the variant of the first context heuristic that matches an existing part of speech
+fn choose(
+    variants: &[(PartOfSpeech, String)],
+    prev: Option<&str>,
+    next: Option<&str>,
+    later: &[&str],
+) -> Variant {
+    let is_content_word = |word: &str| {
+        !OBJECT_MARKERS.contains(&word)
+            && !PREPOSITIONS.contains(&word)
+            && !BASE_FORM_MARKERS.contains(&word)
+            && !VERB_MARKERS.contains(&word)
+            && !ADJECTIVE_MARKERS.contains(&word)
+    };
+
+    let after = |markers: &[&str]| prev.filter(|prev| markers.contains(prev));
+    let before = |markers: &[&str]| next.filter(|next| markers.contains(next));
+    let before_past_later = later.iter().enumerate().find_map(|(index, word)| {
+        let is_last_time = *word == LAST_MARKER
+            && later
+                .get(index + 1)
+                .is_some_and(|next| TIME_NOUNS.contains(next));
+
+        (PAST_NEXT_MARKERS.contains(word) || is_last_time).then_some(*word)
+    });
+
+    let guesses = [
+        (after(&PAST_MARKERS), false, PartOfSpeech::PastTense),
+        (after(&BASE_FORM_MARKERS), false, PartOfSpeech::Verb),
+        (before_past_later, true, PartOfSpeech::PastTense),
+        (after(&ADJECTIVE_MARKERS), false, PartOfSpeech::Adjective),
+        (after(&VERB_MARKERS), false, PartOfSpeech::Verb),
+        (
+            after(&DETERMINERS).filter(|_| next.is_some_and(is_content_word)),
+            false,
+            PartOfSpeech::Adjective,
+        ),
+        (after(&DETERMINERS), false, PartOfSpeech::Noun),
+        (after(&PREPOSITIONS), false, PartOfSpeech::Noun),
+        (before(&OBJECT_MARKERS), true, PartOfSpeech::Verb),
+    ];
+
+    let guess = guesses
+        .iter()
+        .find_map(|(marker, is_next, part_of_speech)| {
+            let marker = marker.as_ref()?;
+            let (_, pronunciation) = variants.iter().find(|(part, _)| part == part_of_speech)?;
+
+            let reason = match is_next {
+                true => format!("before '{}'", marker),
+                false => format!("after '{}'", marker),
+            };
+
+            Some((*part_of_speech, pronunciation, reason))
+        });
+
+    let (part_of_speech, pronunciation, reason) = guess.unwrap_or_else(|| {
+        let (part_of_speech, pronunciation) = &variants[0];
+
+        (*part_of_speech, pronunciation, String::from("default"))
+    });
+
+    Variant {
+        part_of_speech,
+        pronunciation: pronunciation.to_string(),
+        reason,
+    }
+}
+
+#[cfg(test)]
+mod disambiguate {
+    use super::PartOfSpeech;
+    use crate::parser::parse_words;
+
+    fn variants(text: &str) -> Vec<(PartOfSpeech, String, String)> {
+        parse_words(text)
+            .iter()
+            .filter_map(|word| word.variant())
+            .map(|variant| {
+                (
+                    variant.part_of_speech(),
+                    variant.pronunciation().to_string(),
+                    variant.reason().to_string(),
+                )
+            })
+            .collect()
+    }
+
+    fn parts_of_speech(text: &str) -> Vec<PartOfSpeech> {
+        variants(text)
+            .into_iter()
+            .map(|(part_of_speech, _, _)| part_of_speech)
+            .collect()
+    }
+
+    #[test]
+    fn it_should_not_report_variant_of_usual_words() {
+        assert!(variants("Put a cat").is_empty());
+    }
+
+    #[test]
+    fn it_should_disambiguate_read() {
+        assert_eq!(
+            variants("I want to read. I have read it. I read it yesterday"),
+            vec![
+                (
+                    PartOfSpeech::Verb,
+                    String::from("/riːd/"),
+                    String::from("after 'to'")
+                ),
+                (
+                    PartOfSpeech::PastTense,
+                    String::from("/rɛd/"),
+                    String::from("after 'have'")
+                ),
+                (
+                    PartOfSpeech::PastTense,
+                    String::from("/rɛd/"),
+                    String::from("before 'yesterday'")
+                ),
+            ]
+        );
+        assert_eq!(
+            parts_of_speech("We read yesterday"),
+            vec![PartOfSpeech::PastTense]
+        );
+        assert_eq!(
+            parts_of_speech("I read the book last week. I read. Yesterday too"),
+            vec![PartOfSpeech::PastTense, PartOfSpeech::Verb]
+        );
+        assert_eq!(
+            parts_of_speech("I want to read the last chapter"),
+            vec![PartOfSpeech::Verb]
+        );
+        assert_eq!(
+            parts_of_speech("We read the last chapter"),
+            vec![PartOfSpeech::Verb]
+        );
+        assert_eq!(
+            parts_of_speech("We read it last night"),
+            vec![PartOfSpeech::PastTense]
+        );
+    }
+
+    #[test]
+    fn it_should_disambiguate_lead_and_live() {
+        assert_eq!(
+            parts_of_speech("They lead the team, made of lead"),
+            vec![PartOfSpeech::Verb, PartOfSpeech::Noun]
+        );
+        assert_eq!(
+            parts_of_speech("We live here. The live show is live"),
+            vec![
+                PartOfSpeech::Verb,
+                PartOfSpeech::Adjective,
+                PartOfSpeech::Adjective
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_disambiguate_close_wind_and_use() {
+        assert_eq!(
+            parts_of_speech("Close the door, it is very close"),
+            vec![PartOfSpeech::Verb, PartOfSpeech::Adjective]
+        );
+        assert_eq!(
+            parts_of_speech("The wind is strong, wind the clock"),
+            vec![PartOfSpeech::Noun, PartOfSpeech::Verb]
+        );
+        assert_eq!(
+            parts_of_speech("the use of it, you use it"),
+            vec![PartOfSpeech::Noun, PartOfSpeech::Verb]
+        );
+    }
+
+    #[test]
+    fn it_should_choose_default_variant_without_context() {
+        assert_eq!(
+            variants("Wind!"),
+            vec![(
+                PartOfSpeech::Noun,
+                String::from("/wɪnd/"),
+                String::from("default")
+            )]
+        );
+    }
+}
+
+#[cfg(test)]
+mod from_str {
+    use super::{Error, Homographs};
+
+    #[test]
+    fn it_should_parse_bundled_homographs() {
+        assert!(Homographs::english().entries.contains_key("read"));
+    }
+
+    #[test]
+    fn it_should_report_invalid_line() {
+        assert!(matches!(
+            "read Verb".parse::<Homographs>(),
+            Err(Error::Syntax { line: 1, .. })
+        ));
+        assert_eq!(
+            "\nread Past /rɛd/".parse::<Homographs>().unwrap_err(),
+            Error::Syntax {
+                line: 2,
+                message: String::from("unknown part of speech 'Past'")
+            }
+        );
+    }
+}
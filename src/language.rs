@@ -0,0 +1,181 @@
+use crate::acronym::is_initialism;
+use crate::connected::{connect, Link};
+use crate::homograph::disambiguate;
+use crate::lexicon::Lexicon;
+use crate::normalizer::{normalize_text, NormalizedText};
+use crate::options::Options;
+use crate::rules::RuleSet;
+use crate::sound::SoundKind;
+use crate::tokenizer::MID_LETTER_SEPARATORS;
+use crate::word::Word;
+use std::sync::OnceLock;
+
+/// Bundled German rules
+const GERMAN_RULES: &str = include_str!("../data/de.rules");
+
+/// Language of the text: its sound inventory, tokenizer tweaks and rules.
+/// English-specific steps (lexicons of the accents, initialisms, expansion,
+/// homographs and connected speech) are hooks with empty defaults.
+pub trait Language {
+    /// Returns name of the language, e.g. "English"
+    fn name(&self) -> &'static str;
+
+    /// Returns sound kinds of the language
+    fn sound_kinds(&self) -> &'static [SoundKind];
+
+    /// Returns characters that split words, though Unicode word segmentation keeps them inside
+    fn word_separators(&self) -> &'static [char];
+
+    /// Returns rules of the sounds, the rules of the options take priority over the bundled ones
+    fn rules<'a>(&self, options: &'a Options) -> &'a RuleSet;
+
+    /// Returns bundled lexicon checked after the user-defined one
+    fn lexicon(&self, _options: &Options) -> Option<&'static Lexicon> {
+        None
+    }
+
+    /// Returns true if the word is spoken letter by letter
+    fn is_initialism(&self, _word: &str) -> bool {
+        false
+    }
+
+    /// Returns text with numbers, symbols and abbreviations spoken as words
+    fn normalize(&self, _text: &str, _options: &Options) -> NormalizedText {
+        NormalizedText::default()
+    }
+
+    /// Chooses pronunciation variants of the homographs
+    fn disambiguate(&self, _words: &mut [Word]) {}
+
+    /// Returns sound changes across the word boundaries
+    fn connect(&self, _text: &str, _words: &[Word], _options: &Options) -> Vec<Link> {
+        vec![]
+    }
+}
+
+/// English, the default language, with its accents
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct English;
+
+impl Language for English {
+    fn name(&self) -> &'static str {
+        "English"
+    }
+
+    fn sound_kinds(&self) -> &'static [SoundKind] {
+        &[
+            SoundKind::Ptk,
+            SoundKind::Th,
+            SoundKind::W,
+            SoundKind::V,
+            SoundKind::Ng,
+            SoundKind::Ch,
+            SoundKind::Dj,
+            SoundKind::Flap,
+            SoundKind::GlottalStop,
+            SoundKind::B,
+            SoundKind::R,
+            SoundKind::L,
+            SoundKind::Undefined,
+        ]
+    }
+
+    fn word_separators(&self) -> &'static [char] {
+        &MID_LETTER_SEPARATORS
+    }
+
+    fn rules<'a>(&self, options: &'a Options) -> &'a RuleSet {
+        options.rules()
+    }
+
+    fn lexicon(&self, options: &Options) -> Option<&'static Lexicon> {
+        Some(options.dialect.lexicon())
+    }
+
+    fn is_initialism(&self, word: &str) -> bool {
+        is_initialism(word)
+    }
+
+    fn normalize(&self, text: &str, options: &Options) -> NormalizedText {
+        match options.expand {
+            true => normalize_text(text),
+            false => NormalizedText::default(),
+        }
+    }
+
+    fn disambiguate(&self, words: &mut [Word]) {
+        disambiguate(words)
+    }
+
+    fn connect(&self, text: &str, words: &[Word], options: &Options) -> Vec<Link> {
+        connect(text, words, options)
+    }
+}
+
+/// German with ich-Laut and ach-Laut, w as /v/ and final devoicing.
+/// The gender colon stays inside words, e.g. "Lehrer:innen".
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::{highlight_in, German, Options};
+///
+/// assert_eq!(
+///     highlight_in("ich wach", &German, &Options::default()),
+///     "i<span class='Ich'>ch</span> <span class='V'>w</span>a<span class='Ach'>ch</span>"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct German;
+
+impl Language for German {
+    fn name(&self) -> &'static str {
+        "German"
+    }
+
+    fn sound_kinds(&self) -> &'static [SoundKind] {
+        &[
+            SoundKind::Ptk,
+            SoundKind::V,
+            SoundKind::Ng,
+            SoundKind::Ich,
+            SoundKind::Ach,
+            SoundKind::Devoiced,
+            SoundKind::Undefined,
+        ]
+    }
+
+    fn word_separators(&self) -> &'static [char] {
+        &[]
+    }
+
+    fn rules<'a>(&self, options: &'a Options) -> &'a RuleSet {
+        static RULES: OnceLock<RuleSet> = OnceLock::new();
+
+        options.rules.as_ref().unwrap_or_else(|| {
+            RULES.get_or_init(|| GERMAN_RULES.parse().expect("bundled rules are valid"))
+        })
+    }
+}
+
+#[cfg(test)]
+mod sound_kinds {
+    use super::{English, German, Language};
+    use crate::options::Options;
+
+    #[test]
+    fn it_should_have_rules_of_own_sound_kinds() {
+        let options = Options::default();
+
+        for language in [&English as &dyn Language, &German] {
+            for rule in language.rules(&options).rules() {
+                assert!(
+                    language.sound_kinds().contains(&rule.kind()),
+                    "{} has no {:?}",
+                    language.name(),
+                    rule.kind()
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,281 @@
+use crate::error::Error;
+use crate::scanner::Scanner;
+use crate::sound::{Sound, SoundKind};
+use std::collections::HashMap;
+use std::str::FromStr;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Comment line prefix of the lexicon source
+const COMMENT_PREFIX: char = '#';
+
+/// Separator between the word and its segments in the lexicon source
+const ENTRY_SEPARATOR: char = '=';
+
+/// Separator between the segment and its sound kind in the lexicon source
+const KIND_SEPARATOR: char = '/';
+
+/// User-defined pronunciations: words with their explicit sound segmentations.
+/// Entries of the lexicon take priority over the rules.
+///
+/// The text format has one entry per line: the word, `=` and the segments separated by spaces.
+/// A segment has a sound kind after `/`, a segment without the kind is `Undefined`.
+/// Lines started with `#` are comments.
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::{highlight_with, Lexicon, Options, SoundKind};
+///
+/// let mut lexicon: Lexicon = "
+/// ## brand names
+/// nginx = n g i n x
+/// "
+/// .parse()
+/// .unwrap();
+///
+/// lexicon.insert("Thomas", &[("th", SoundKind::Ptk), ("omas", SoundKind::Undefined)]).unwrap();
+///
+/// let options = Options {
+///     lexicon,
+///     ..Options::default()
+/// };
+///
+/// assert_eq!(highlight_with("Thomas uses nginx", &options), "<span class='Ptk'>Th</span>omas uses nginx");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Lexicon {
+    entries: HashMap<String, Vec<(String, SoundKind)>>,
+}
+
+impl Lexicon {
+    /// Creates new empty Lexicon
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts the word with its segments, the segments should make up the word
+    pub fn insert(&mut self, word: &str, segments: &[(&str, SoundKind)]) -> Result<(), Error> {
+        let key = normalize(word);
+
+        let joined = segments
+            .iter()
+            .map(|(segment, _)| normalize(segment))
+            .collect::<String>();
+
+        if key.is_empty() {
+            return Err(Error::InvalidEntry {
+                word: word.to_string(),
+                message: String::from("the word is empty"),
+            });
+        }
+
+        if joined != key {
+            return Err(Error::InvalidEntry {
+                word: word.to_string(),
+                message: format!("segments make up '{}'", joined),
+            });
+        }
+
+        let segments = segments
+            .iter()
+            .filter(|(segment, _)| !segment.is_empty())
+            .map(|(segment, kind)| (normalize(segment), *kind))
+            .collect();
+
+        self.entries.insert(key, segments);
+
+        Ok(())
+    }
+
+    /// Returns segments of the word
+    pub fn get(&self, word: &str) -> Option<&[(String, SoundKind)]> {
+        self.entries.get(&normalize(word)).map(Vec::as_slice)
+    }
+
+    /// Returns count of the entries
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if there are no entries
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Parse the word graphemes to sounds of its segments, the source text of the word is kept
+    pub(crate) fn parse_word(&self, word: &str, mut scanner: Scanner) -> Option<Vec<Sound>> {
+        let segments = self.get(word)?;
+        let rule = format!("lexicon: {}", normalize(word));
+
+        let sounds = segments
+            .iter()
+            .map(|(segment, kind)| {
+                let text = (0..segment.graphemes(true).count())
+                    .map(|_| scanner.pop().to_string())
+                    .collect::<String>();
+
+                match kind {
+                    SoundKind::Undefined => Sound::new(*kind, text),
+                    kind => Sound::with_rule(*kind, text, rule.clone()),
+                }
+            })
+            .collect();
+
+        Some(sounds)
+    }
+}
+
+impl FromStr for Lexicon {
+    type Err = Error;
+
+    /// Parses the lexicon from the text format
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let mut lexicon = Lexicon::new();
+
+        for (index, line) in source.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with(COMMENT_PREFIX) {
+                continue;
+            }
+
+            let syntax_error = |message: String| Error::Syntax {
+                line: index + 1,
+                message,
+            };
+
+            let (word, segments) = line
+                .split_once(ENTRY_SEPARATOR)
+                .ok_or_else(|| syntax_error(format!("expected '{}'", ENTRY_SEPARATOR)))?;
+
+            let segments = segments
+                .split_whitespace()
+                .map(|segment| match segment.split_once(KIND_SEPARATOR) {
+                    Some((text, kind)) => Ok((text, kind.parse::<SoundKind>()?)),
+                    None => Ok((segment, SoundKind::Undefined)),
+                })
+                .collect::<Result<Vec<(&str, SoundKind)>, Error>>()
+                .map_err(|error| syntax_error(error.to_string()))?;
+
+            lexicon
+                .insert(word.trim(), &segments)
+                .map_err(|error| syntax_error(error.to_string()))?;
+        }
+
+        Ok(lexicon)
+    }
+}
+
+/// Returns lowercase NFC normalized text
+fn normalize(text: &str) -> String {
+    text.nfc().collect::<String>().to_lowercase()
+}
+
+#[cfg(test)]
+mod insert {
+    use super::{Error, Lexicon, SoundKind};
+
+    #[test]
+    fn it_should_insert_entry() {
+        let mut lexicon = Lexicon::new();
+
+        lexicon
+            .insert(
+                "Nguyen",
+                &[("Ng", SoundKind::Ng), ("uyen", SoundKind::Undefined)],
+            )
+            .unwrap();
+
+        assert_eq!(lexicon.len(), 1);
+        assert_eq!(
+            lexicon.get("NGUYEN"),
+            Some(
+                &[
+                    (String::from("ng"), SoundKind::Ng),
+                    (String::from("uyen"), SoundKind::Undefined)
+                ][..]
+            )
+        );
+    }
+
+    #[test]
+    fn it_should_not_insert_mismatched_segments() {
+        let mut lexicon = Lexicon::new();
+
+        assert_eq!(
+            lexicon.insert(
+                "nginx",
+                &[("ng", SoundKind::Ng), ("in", SoundKind::Undefined)]
+            ),
+            Err(Error::InvalidEntry {
+                word: String::from("nginx"),
+                message: String::from("segments make up 'ngin'")
+            })
+        );
+        assert!(lexicon.is_empty());
+    }
+
+    #[test]
+    fn it_should_not_insert_empty_word() {
+        let mut lexicon = Lexicon::new();
+
+        assert!(lexicon.insert("", &[]).is_err());
+    }
+}
+
+#[cfg(test)]
+mod from_str {
+    use super::{Error, Lexicon, SoundKind};
+
+    #[test]
+    fn it_should_parse_lexicon() {
+        let lexicon: Lexicon = "
+            # comment
+
+            thames = th/Ptk a m e s
+            Joaquin = J/Undefined oa qu/Ptk in
+        "
+        .parse()
+        .unwrap();
+
+        assert_eq!(lexicon.len(), 2);
+        assert_eq!(
+            lexicon.get("joaquin").unwrap()[2],
+            (String::from("qu"), SoundKind::Ptk)
+        );
+    }
+
+    #[test]
+    fn it_should_report_missing_separator() {
+        assert_eq!(
+            "thames th a m e s".parse::<Lexicon>(),
+            Err(Error::Syntax {
+                line: 1,
+                message: String::from("expected '='")
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_report_unknown_kind() {
+        assert_eq!(
+            "\nthames = th/T ames".parse::<Lexicon>(),
+            Err(Error::Syntax {
+                line: 2,
+                message: String::from("unknown sound kind 'T'")
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_report_invalid_entry() {
+        assert_eq!(
+            "thames = th ame".parse::<Lexicon>(),
+            Err(Error::Syntax {
+                line: 1,
+                message: String::from("invalid entry 'thames': segments make up 'thame'")
+            })
+        );
+    }
+}
@@ -6,10 +6,60 @@
 //!
 //! The library has functions (`parse`, `serialize`) to parse text (`AsRef<str>`) to `Vec<Sound>` and serialize `Vec<Sound>` to `String`. `Sound` struct has information about English sound. `highlight` function adds `html` tags to text that can be used to highlight sounds in the browser via `css`.
 //!
+//! Words are found by `tokenize` function with Unicode word segmentation ([UAX #29](https://unicode.org/reports/tr29/)), so newlines, tabs, quotes, brackets, dashes and digits are handled as you expect.
+//!
+//! Words are scanned by grapheme clusters, so combining accents and emoji are never split. `parse_with` and `highlight_with` functions accept `Options`, e.g. `nfc` to apply NFC normalization before scanning.
+//!
+//! `parse_words` function parses text to `Vec<Word>`: every `Word` has its text, span, normalized form and sounds, the separators between words are kept as well, so `serialize` can turn words back to the same text.
+//!
+//! `parse_paragraphs` function groups words to `Vec<Paragraph>` of `Sentence`s. Sentence ends are detected with abbreviations in mind ("Dr.", "e.g."), a blank line ends the paragraph.
+//!
+//! With `expand` option numbers, ordinals, currencies, times, symbols and common abbreviations are expanded into words ("3rd" is parsed as "third"), `normalize_text` function returns such text with a mapping back to the source spans.
+//!
+//! Initialisms ("FBI", "HTML") are parsed by letter names, e.g. "tee" in "HTML" has an aspirated `T`. Acronyms spoken as words ("NATO", "ASAP") are parsed as usual words.
+//!
+//! Brand names, jargon and proper names can be added to `Lexicon` with explicit sound segmentations (`thames = th/Ptk a m e s`), its entries take priority over the rules.
+//!
+//! Rules of the sounds are data: `RuleSet` is loaded from the text format (`[ptc] -> Ptk / # _` is "p, t or c at the word start"), the bundled English rules are in `data/en.rules`. Rules loaded at runtime with `RuleSet::from_file` can be passed with `rules` option.
+//!
+//! Each `Sound` can carry the rule that produced it (a rule description such as "word-final p, t or c → Ptk", "lexicon: thames" or "initialism: letter name of T"), `explain` function returns the matched rules per sound with their spans in the source text.
+//!
+//! `dialect` option switches rules and lexicons of the accent: `Dialect::GeneralAmerican` (by default) and `Dialect::ReceivedPronunciation`, e.g. "tune" has `Ch` and "duke" has `Dj` in RP. Bundled data of the accents is in `data/en-us.rules`, `data/en-gb.rules` and `data/en-gb.lexicon`.
+//!
+//! The accents detect t-flapping (`Flap` in "butter", "city" and "a lot of" in General American) and the glottal stop (`GlottalStop` in "button" in RP) by the syllable context. Right context of a rule can look into the next word after `#`, e.g. `t -> Flap / V _ # of #`, and alternatives in parentheses match one of the sequences, e.g. `(tt|t) -> GlottalStop / V _ (on|en|ain) #`.
+//!
+//! Words are linked in the connected speech: `parse_links` function returns sound changes across word boundaries (`Link`s of coalescence in "did you" and "don't you", reduction of "want to" to "wanna", linking r of Received Pronunciation and elision of t in "last night", each refers to its words by `Link::words` indices), with `connected_speech` option `highlight_with` wraps them in spans over the boundary.
+//!
+//! Homographs ("read", "lead", "live", "close", "wind", "use") get the pronunciation variant chosen by the neighbor words and simple part of speech heuristics, `Word::variant` returns it with the reason of the choice. Bundled data is in `data/en.homographs`.
+//!
+//! `Language` trait owns the sound inventory, tokenizer tweaks and rules of the language: `parse_in`, `parse_words_in` and `highlight_in` functions are generic over it. `English` is the default language, `German` has `Ich` and `Ach` for "ich" and "Bach", `w` as `V`, final devoicing (`Devoiced` in "Hund") and keeps the gender colon inside words ("Lehrer:innen").
+//!
+//! Learners with the same first language struggle with the same sounds: `Profile` is a difficulty profile of such learners (`Profile::bundled` with "ru", "es", "ja" or "zh"), with `profile` option only its sounds are highlighted, weighted by difficulty, and its rules go first, e.g. `B` and `V` for Spanish speakers, `R` and `L` for Japanese speakers. A profile can weight final consonants (`Sound::is_final`, e.g. "g" and "s" in "dogs") with `final = 1.5` line, they are highlighted in spans of the `FinalConsonant` class, e.g. for Chinese speakers. Bundled profiles are in `data/profiles`.
+//!
+//! `score` function rates pronunciation difficulty of the text, its sentences and words by the sounds (weighted by the learner profile with `score_with`), consonant clusters and rare spellings ("ough", "kn"), `Difficulty` has the breakdown per `SoundKind` and `TextDifficulty::ranking` returns the words from the hardest one.
+//!
+//! `stats` function counts sounds of the text: `SoundStats` has counts, percentages and density per word of every `SoundKind`, the most frequent words of the sound and the positions in the word (`Position::Initial`, `Position::Medial`, `Position::Final`). `text-to-sounds stats` command of the CLI and the website show them.
+//!
+//! `minimal_pairs` function finds pairs of words that differ only in the sounds of the `Contrast` (`Th/s` for "think" and "sink", `V/W` for "vet" and "wet", `Ch/sh` for "chip" and "ship") in the supplied word list, `text-to-sounds pairs` command of the CLI exports them.
+//!
+//! `rhyme_key` function returns sounds of the word from the last stressed vowel onward ("ake" in "cake"), `rhymes` function groups rhyming words of the text and `alliterations` function finds runs of words with the same initial sound ("Peter Piper picked a peck"). With `rhymes` option `highlight_with` wraps rhyming words in spans of the `Rhyme` class and the number of the group (`Rhyme1`, `Rhyme2`).
+//!
+//! `phonetic_distance` function measures the edit distance between the sounds of two words, substitutions are weighted by articulatory `Features` of the sounds (voicing, place and manner, e.g. "th" and "s" are closer than "th" and "m", while "c" and "k" with the same features match), `PhoneticDistance` has the alignment of the sounds too.
+//!
+//! `soundex`, `metaphone` and `double_metaphone` functions encode words for fuzzy name matching ("Robert" and "Rupert" are both "R163" in Soundex), `DoubleMetaphone` has the primary code and the alternate one for Germanic, Slavic and other pronunciations ("Schmidt" is "XMT" and "SMT").
+//!
+//! `assess` function compares the target text with the recognized speech of the learner (text of the speech recognizer, or sounds mapped from its phonemes with `assess_sounds_with`): the sounds are aligned and `Assessment` reports substitutions, deletions and insertions per `SoundKind`, e.g. "Th realized as s in 'think'", punctuation and digits are skipped and spelling variants with the same features match, e.g. "cat" and "kat". With `recognized` option `highlight_with` wraps the errors in spans of the `Error` class and the edit (`Error Substitution`, `Error Deletion`, `Error Insertion`).
+//!
+//! `drill` function generates tongue twisters and drills from the word list: practice sentences or word lists (`DrillFormat`) with a high density of the target sounds of `DrillOptions`, its length, difficulty `Level` of the words and the seed of the random generator, so the same seed gives the same drill. `text-to-sounds drill` command of the CLI generates them too, with `--items`, `--length`, `--level` and `--seed` flags.
+//!
+//! `readability` function measures readability of the text with the real syllable counts of its sounds (`syllables` function counts them): `Readability` has Flesch Reading Ease, Flesch-Kincaid Grade, SMOG and Coleman-Liau. `text-to-sounds readability` command of the CLI and `readability_wasm` show them.
+//!
+//! `ssml` function renders the text as SSML for text-to-speech engines: words with the defined sounds are wrapped in `<phoneme alphabet="ipa">` with the pronunciation of the parse (`θ` for `Th`, `tʰ` for `Ptk` aspirated unless final, other letters are approximated by spelling patterns, rhotic or non-rhotic by the dialect; initialisms such as "TV" and words with letters out of the English alphabet such as "café" are left to the engine), with `SsmlOptions` words with the target sounds are wrapped in `<emphasis>` and sentences end with `<break>`. The output is XML-escaped.
+//!
 //! ```rust
 //! use uuid::Uuid;
 //!
-//! // English sound kinds
+//! // Sound kinds of the languages
 //! enum SoundKind {
 //!     Ptk,
 //!     Th,
@@ -18,6 +68,14 @@
 //!     Ng,
 //!     Ch,
 //!     Dj,
+//!     Flap,
+//!     GlottalStop,
+//!     Ich,
+//!     Ach,
+//!     Devoiced,
+//!     B,
+//!     R,
+//!     L,
 //!     Undefined,
 //! }
 //!
@@ -26,6 +84,8 @@
 //!     id: Uuid,
 //!     kind: SoundKind,
 //!     text: String,
+//!     rule: Option<String>,
+//!     is_final: bool,
 //! }
 //! ```
 //!
@@ -78,15 +138,74 @@
 //!
 //! Also, you can consider tests inside the files.
 
+mod acronym;
+mod aligner;
+mod assessor;
+mod connected;
+mod contrast;
+mod dialect;
+mod encoder;
+mod error;
+mod explainer;
+mod generator;
+mod grader;
 mod highlighter;
+mod homograph;
+mod language;
+mod lexicon;
+mod narrator;
+mod normalizer;
+mod options;
 mod parser;
+mod profile;
+mod rhymer;
+mod rules;
 mod scanner;
+mod scorer;
+mod segmenter;
 mod serializer;
 mod sound;
+mod statistics;
+mod tokenizer;
 mod wasm;
+mod word;
 
-pub use crate::highlighter::highlight;
-pub use crate::parser::parse;
-pub use crate::serializer::serialize;
-pub use crate::sound::{Sound, SoundKind};
-pub use crate::wasm::highlight_wasm;
+pub use crate::aligner::{
+    phonetic_distance, phonetic_distance_with, AlignedSounds, Edit, PhoneticDistance,
+};
+pub use crate::assessor::{assess, assess_sounds_with, assess_with, Assessment, Mispronunciation};
+pub use crate::connected::{Link, LinkKind};
+pub use crate::contrast::{minimal_pairs, minimal_pairs_with, Contrast, MinimalPair};
+pub use crate::dialect::Dialect;
+pub use crate::encoder::{double_metaphone, metaphone, soundex, DoubleMetaphone};
+pub use crate::error::Error;
+pub use crate::explainer::{explain, explain_with, Explanation};
+pub use crate::generator::{drill, drill_with, Drill, DrillFormat, DrillOptions, Level};
+pub use crate::grader::{readability, readability_with, syllables, Readability};
+pub use crate::highlighter::{highlight, highlight_in, highlight_with};
+pub use crate::homograph::{PartOfSpeech, Variant};
+pub use crate::language::{English, German, Language};
+pub use crate::lexicon::Lexicon;
+pub use crate::narrator::{ssml, ssml_with, SsmlOptions};
+pub use crate::normalizer::{normalize_text, Expansion, NormalizedText};
+pub use crate::options::Options;
+pub use crate::parser::{
+    parse, parse_in, parse_links, parse_links_with, parse_paragraphs, parse_paragraphs_with,
+    parse_with, parse_words, parse_words_in, parse_words_with,
+};
+pub use crate::profile::Profile;
+pub use crate::rhymer::{
+    alliterations, alliterations_with, rhyme_key, rhymes, rhymes_with, WordGroup,
+};
+pub use crate::rules::{Rule, RuleSet};
+pub use crate::scorer::{score, score_with, Difficulty, Scored, TextDifficulty};
+pub use crate::segmenter::{Paragraph, Sentence};
+pub use crate::serializer::{serialize, Serializable};
+pub use crate::sound::{Features, Manner, Place, Sound, SoundKind, Voicing};
+pub use crate::statistics::{stats, stats_with, KindStats, Position, SoundStats};
+pub use crate::tokenizer::{tokenize, Token, TokenKind};
+pub use crate::wasm::{
+    highlight_wasm, highlight_with_lexicon_wasm, highlight_with_profile_wasm, readability_wasm,
+    stats_wasm,
+};
+pub use crate::word::Word;
@@ -0,0 +1,233 @@
+use std::env;
+use std::io::{self, Read};
+use std::process::ExitCode;
+use std::str::FromStr;
+use text_to_sounds::{
+    drill, highlight, minimal_pairs, readability, stats, Contrast, DrillOptions, Level, SoundKind,
+};
+
+const USAGE: &str = "Usage: text-to-sounds <command> [arguments] [text]
+
+Commands:
+    highlight              Highlight sounds of the text with html tags
+    stats                  Show frequency statistics of the sounds of the text
+    readability            Show readability metrics of the text (Flesch, Flesch-Kincaid, SMOG, Coleman-Liau)
+    pairs <contrast>       Export minimal pairs of the contrast (e.g. Th/s) from the word list,
+                           one tab-separated pair per line
+    drill <kinds> [flags]  Generate practice sentences with the sounds (e.g. Th,V) from the word list
+
+Flags of drill:
+    --items <number>       Number of the sentences, 5 by default
+    --length <number>      Number of the words of every sentence, 6 by default
+    --level <level>        Difficulty of the words: easy, medium or hard, all the words by default
+    --seed <number>        Seed of the random generator, the same seed gives the same drill, 0 by default
+
+The text is read from stdin if it is not passed.";
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+
+    match run(&args) {
+        Ok(output) => {
+            println!("{}", output);
+
+            ExitCode::SUCCESS
+        }
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Runs the command of the arguments and returns its output
+fn run(args: &[String]) -> Result<String, String> {
+    let (command, args) = args.split_first().ok_or("missing command")?;
+
+    match command.as_str() {
+        "highlight" => Ok(highlight(read_text(args)?)),
+        "stats" => Ok(stats(read_text(args)?).to_string().trim_end().to_string()),
+        "readability" => Ok(readability(read_text(args)?)
+            .to_string()
+            .trim_end()
+            .to_string()),
+        "pairs" => {
+            let (contrast, args) = args.split_first().ok_or("missing contrast")?;
+            let contrast = contrast
+                .parse::<Contrast>()
+                .map_err(|error| error.to_string())?;
+
+            let pairs = minimal_pairs(read_text(args)?.split_whitespace(), &contrast)
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>();
+
+            Ok(pairs.join("\n"))
+        }
+        "drill" => {
+            let (kinds, args) = args.split_first().ok_or("missing sound kinds")?;
+            let kinds = kinds
+                .split(',')
+                .map(|kind| kind.trim().parse::<SoundKind>())
+                .collect::<Result<Vec<SoundKind>, _>>()
+                .map_err(|error| error.to_string())?;
+
+            let (drill_options, args) = read_drill_options(kinds, args)?;
+
+            drill(read_text(args)?.split_whitespace(), &drill_options)
+                .map(|drill| drill.to_string())
+                .map_err(|error| error.to_string())
+        }
+        command => Err(format!("unknown command '{}'", command)),
+    }
+}
+
+/// Returns the drill options of the flags and the arguments after them
+fn read_drill_options(
+    kinds: Vec<SoundKind>,
+    mut args: &[String],
+) -> Result<(DrillOptions, &[String]), String> {
+    let mut drill_options = DrillOptions {
+        kinds,
+        ..DrillOptions::default()
+    };
+
+    while let Some((flag, rest)) = args.split_first().filter(|(arg, _)| arg.starts_with("--")) {
+        let (value, rest) = rest
+            .split_first()
+            .ok_or_else(|| format!("missing value of '{}'", flag))?;
+
+        match flag.as_str() {
+            "--items" => drill_options.items = read_number(flag, value)?,
+            "--length" => drill_options.length = read_number(flag, value)?,
+            "--level" => drill_options.level = Some(value.parse::<Level>()?),
+            "--seed" => drill_options.seed = read_number(flag, value)?,
+            flag => return Err(format!("unknown flag '{}'", flag)),
+        }
+
+        args = rest;
+    }
+
+    Ok((drill_options, args))
+}
+
+/// Returns the number of the flag value
+fn read_number<T: FromStr>(flag: &str, value: &str) -> Result<T, String> {
+    value
+        .parse::<T>()
+        .map_err(|_| format!("invalid value '{}' of '{}'", value, flag))
+}
+
+/// Returns the text of the arguments or stdin if there are no arguments
+fn read_text(args: &[String]) -> Result<String, String> {
+    if !args.is_empty() {
+        return Ok(args.join(" "));
+    }
+
+    let mut text = String::new();
+
+    io::stdin()
+        .read_to_string(&mut text)
+        .map_err(|error| format!("io error: {}", error))?;
+
+    Ok(text)
+}
+
+#[cfg(test)]
+mod run {
+    use super::run;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn it_should_run_commands() {
+        assert_eq!(
+            run(&args(&["highlight", "Put"])),
+            Ok(String::from(
+                "<span class='Ptk'>P</span>u<span class='Ptk'>t</span>"
+            ))
+        );
+        assert!(run(&args(&["stats", "Put"]))
+            .unwrap()
+            .starts_with("Sounds: 3, words: 1"));
+        assert!(run(&args(&["readability", "Put it."]))
+            .unwrap()
+            .starts_with("Words: 2, sentences: 1, syllables: 2"));
+    }
+
+    #[test]
+    fn it_should_export_minimal_pairs() {
+        assert_eq!(
+            run(&args(&["pairs", "V/W", "wet vet", "wine\nvine"])),
+            Ok(String::from("vet\twet\nvine\twine"))
+        );
+        assert_eq!(
+            run(&args(&["pairs", "V", "wet"])),
+            Err(String::from("invalid contrast 'V'"))
+        );
+    }
+
+    #[test]
+    fn it_should_generate_drill() {
+        assert_eq!(
+            run(&args(&[
+                "drill",
+                "Th,V",
+                "--items",
+                "2",
+                "--length",
+                "3",
+                "--seed",
+                "7",
+                "three thin thieves think thought very vet cat dog"
+            ])),
+            Ok(String::from("Thought three very.\nThin vet think."))
+        );
+        assert_eq!(
+            run(&args(&[
+                "drill",
+                "Th",
+                "--items",
+                "1",
+                "--length",
+                "2",
+                "--level",
+                "hard",
+                "thin think thought through three"
+            ])),
+            Ok(String::from("Thought through."))
+        );
+        assert_eq!(
+            run(&args(&["drill", "Th", "--items", "many", "thin"])),
+            Err(String::from("invalid value 'many' of '--items'"))
+        );
+        assert_eq!(
+            run(&args(&["drill", "Th", "--level", "tough", "thin"])),
+            Err(String::from("unknown level 'tough'"))
+        );
+        assert_eq!(
+            run(&args(&["drill", "Th", "--pace", "1", "thin"])),
+            Err(String::from("unknown flag '--pace'"))
+        );
+        assert_eq!(
+            run(&args(&["drill", "Th,Sh", "thin"])),
+            Err(String::from("unknown sound kind 'Sh'"))
+        );
+        assert_eq!(
+            run(&args(&["drill", "V", "thin"])),
+            Err(String::from("no words with the target sounds of the drill"))
+        );
+    }
+
+    #[test]
+    fn it_should_report_unknown_command() {
+        assert_eq!(
+            run(&args(&["shout", "Put"])),
+            Err(String::from("unknown command 'shout'"))
+        );
+        assert_eq!(run(&[]), Err(String::from("missing command")));
+    }
+}
@@ -0,0 +1,654 @@
+use crate::acronym::is_initialism;
+use crate::dialect::Dialect;
+use crate::options::Options;
+use crate::parser::parse_paragraphs_with;
+use crate::sound::{Sound, SoundKind};
+use crate::word::Word;
+
+/// Words with the voiced "th", e.g. "ð" in "this"
+const VOICED_TH_WORDS: [&str; 20] = [
+    "the", "this", "that", "these", "those", "them", "they", "their", "theirs", "there", "then",
+    "than", "though", "thus", "thee", "thy", "with", "within", "without", "smooth",
+];
+
+/// Common words with their IPA which the spelling patterns miss, e.g. "ðə" of "the"
+const IRREGULAR_WORDS: [(&str, &str); 3] = [("the", "ðə"), ("to", "tʰuː"), ("two", "tʰuː")];
+
+/// Letter groups of the spelling with their IPA, longer groups go first
+const LETTER_GROUPS: [(&str, &str); 25] = [
+    ("igh", "aɪ"),
+    ("tch", "tʃ"),
+    ("dge", "dʒ"),
+    ("sh", "ʃ"),
+    ("ch", "tʃ"),
+    ("ph", "f"),
+    ("wh", "w"),
+    ("ck", "k"),
+    ("qu", "kw"),
+    ("ng", "ŋ"),
+    ("ee", "iː"),
+    ("ea", "iː"),
+    ("oo", "uː"),
+    ("ai", "eɪ"),
+    ("ay", "eɪ"),
+    ("ey", "eɪ"),
+    ("oa", "oʊ"),
+    ("ou", "aʊ"),
+    ("ow", "aʊ"),
+    ("oi", "ɔɪ"),
+    ("oy", "ɔɪ"),
+    ("au", "ɔː"),
+    ("aw", "ɔː"),
+    ("ie", "iː"),
+    ("ue", "uː"),
+];
+
+/// Vowels before "r" with their IPA in the rhotic General American, e.g. "ɝ" in "third"
+const RHOTIC_GROUPS: [(&str, &str); 5] = [
+    ("ar", "ɑɹ"),
+    ("or", "ɔɹ"),
+    ("er", "ɚ"),
+    ("ir", "ɝ"),
+    ("ur", "ɝ"),
+];
+
+/// Vowels before "r" with their IPA in the non-rhotic Received Pronunciation, e.g. "ɜː" in "third"
+const NON_RHOTIC_GROUPS: [(&str, &str); 5] = [
+    ("ar", "ɑː"),
+    ("or", "ɔː"),
+    ("er", "ə"),
+    ("ir", "ɜː"),
+    ("ur", "ɜː"),
+];
+
+/// Letters with their IPA
+const LETTERS: [(char, &str); 26] = [
+    ('a', "æ"),
+    ('b', "b"),
+    ('c', "k"),
+    ('d', "d"),
+    ('e', "ɛ"),
+    ('f', "f"),
+    ('g', "ɡ"),
+    ('h', "h"),
+    ('i', "ɪ"),
+    ('j', "dʒ"),
+    ('k', "k"),
+    ('l', "l"),
+    ('m', "m"),
+    ('n', "n"),
+    ('o', "ɑ"),
+    ('p', "p"),
+    ('q', "k"),
+    ('r', "ɹ"),
+    ('s', "s"),
+    ('t', "t"),
+    ('u', "ʌ"),
+    ('v', "v"),
+    ('w', "w"),
+    ('x', "ks"),
+    ('y', "i"),
+    ('z', "z"),
+];
+
+/// Long vowels before the silent final "e", e.g. "eɪ" in "make"
+const LONG_VOWELS: [(char, &str); 5] = [
+    ('a', "eɪ"),
+    ('e', "iː"),
+    ('i', "aɪ"),
+    ('o', "oʊ"),
+    ('u', "juː"),
+];
+
+/// Letters of the vowels in the spelling
+const VOWEL_LETTERS: [char; 6] = ['a', 'e', 'i', 'o', 'u', 'y'];
+
+/// Letters after which "c" is soft, e.g. "s" in "city"
+const SOFT_C_BEFORE: [char; 3] = ['e', 'i', 'y'];
+
+/// Options of the SSML output
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::{SoundKind, SsmlOptions};
+///
+/// let ssml_options = SsmlOptions {
+///     emphasis: vec![SoundKind::Th],
+///     sentence_break: Some(500),
+/// };
+///
+/// assert!(SsmlOptions::default().emphasis.is_empty());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SsmlOptions {
+    /// Words with the sounds of these kinds are wrapped in `<emphasis>`
+    pub emphasis: Vec<SoundKind>,
+    /// Time of the `<break>` after every sentence but the last one in milliseconds
+    pub sentence_break: Option<u32>,
+}
+
+/// Render the text as SSML for text-to-speech engines: words with the defined sounds
+/// are wrapped in `<phoneme alphabet="ipa">` with the pronunciation of the parse
+///
+/// IPA of the defined sounds follows their kinds (`θ` for `Th`, `tʰ` for `Ptk` aspirated unless final),
+/// other letters of the words are approximated by common spelling patterns,
+/// "r" after a vowel is pronounced in General American only, e.g. "θɝd" and "θɜːd" of "third".
+/// Initialisms and words with letters out of the English alphabet are left to the engine, e.g. "TV" and "café".
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::ssml;
+///
+/// assert_eq!(
+///     ssml("I think & sing"),
+///     "<speak version=\"1.1\" xmlns=\"http://www.w3.org/2001/10/synthesis\" xml:lang=\"en-US\">I <phoneme alphabet=\"ipa\" ph=\"θɪŋk\">think</phoneme> &amp; <phoneme alphabet=\"ipa\" ph=\"sɪŋ\">sing</phoneme></speak>"
+/// );
+/// ```
+pub fn ssml<T: AsRef<str>>(text: T) -> String {
+    ssml_with(text, &SsmlOptions::default(), &Options::default())
+}
+
+/// Render the text as SSML with the emphasis and the breaks of the SSML options and the options
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::{ssml_with, Options, SoundKind, SsmlOptions};
+///
+/// let ssml_options = SsmlOptions {
+///     emphasis: vec![SoundKind::V],
+///     sentence_break: Some(300),
+/// };
+///
+/// let ssml = ssml_with("Very good. Bye", &ssml_options, &Options::default());
+///
+/// assert!(ssml.contains("<emphasis level=\"moderate\"><phoneme alphabet=\"ipa\" ph=\"vɛɹi\">Very</phoneme></emphasis>"));
+/// assert!(ssml.contains(". <break time=\"300ms\"/>Bye"));
+/// ```
+pub fn ssml_with<T: AsRef<str>>(text: T, ssml_options: &SsmlOptions, options: &Options) -> String {
+    let lang = match options.dialect {
+        Dialect::GeneralAmerican => "en-US",
+        Dialect::ReceivedPronunciation => "en-GB",
+    };
+
+    let mut result_text = format!(
+        "<speak version=\"1.1\" xmlns=\"http://www.w3.org/2001/10/synthesis\" xml:lang=\"{}\">",
+        lang
+    );
+
+    let paragraphs = parse_paragraphs_with(text, options);
+    let sentences = paragraphs
+        .iter()
+        .flat_map(|paragraph| paragraph.sentences())
+        .collect::<Vec<_>>();
+
+    for (index, sentence) in sentences.iter().enumerate() {
+        for word in sentence.words() {
+            render_word(word, ssml_options, options.dialect, &mut result_text);
+        }
+
+        if let Some(time) = ssml_options.sentence_break {
+            if index + 1 < sentences.len() {
+                result_text.push_str(&format!("<break time=\"{}ms\"/>", time));
+            }
+        }
+    }
+
+    result_text.push_str("</speak>");
+
+    result_text
+}
+
+/// Renders the word with its pronunciation if it has the defined sounds
+fn render_word(
+    word: &Word,
+    ssml_options: &SsmlOptions,
+    dialect: Dialect,
+    result_text: &mut String,
+) {
+    let is_defined = |sound: &Sound| *sound.kind() != SoundKind::Undefined;
+
+    let ipa = match word.is_separator() || !word.sounds().iter().any(is_defined) {
+        true => None,
+        false => ipa(word, dialect),
+    };
+
+    let Some(ipa) = ipa else {
+        result_text.push_str(&escape(word.text()));
+
+        return;
+    };
+
+    let is_emphasized = word
+        .sounds()
+        .iter()
+        .any(|sound| ssml_options.emphasis.contains(sound.kind()));
+
+    if is_emphasized {
+        result_text.push_str("<emphasis level=\"moderate\">");
+    }
+
+    result_text.push_str(&format!(
+        "<phoneme alphabet=\"ipa\" ph=\"{}\">{}</phoneme>",
+        escape(&ipa),
+        escape(word.text())
+    ));
+
+    if is_emphasized {
+        result_text.push_str("</emphasis>");
+    }
+}
+
+/// Unit of the pronunciation: a letter of the undefined sound or IPA of the defined one
+#[derive(Debug, Clone, PartialEq)]
+enum Unit {
+    Letter(char),
+    Sound(String),
+}
+
+/// Returns IPA of the word by its sounds in the dialect,
+/// `None` if the spelling is not transcribed reliably: initialisms and letters out of the English alphabet
+fn ipa(word: &Word, dialect: Dialect) -> Option<String> {
+    let normalized = word.normalized().to_lowercase();
+
+    if let Some((_, ipa)) = IRREGULAR_WORDS
+        .iter()
+        .find(|(irregular, _)| *irregular == normalized)
+    {
+        return Some(ipa.to_string());
+    }
+
+    let is_foreign = |c: char| c.is_alphabetic() && !c.is_ascii_alphabetic();
+
+    if is_initialism(word.text()) || word.text().chars().any(is_foreign) {
+        return None;
+    }
+
+    let mut units: Vec<Unit> = vec![];
+
+    for (index, sound) in word.sounds().iter().enumerate() {
+        let text = sound.text().to_lowercase();
+
+        let unit = match sound.kind() {
+            SoundKind::Undefined => {
+                units.extend(
+                    text.chars()
+                        .filter(char::is_ascii_alphabetic)
+                        .map(Unit::Letter),
+                );
+
+                continue;
+            }
+            SoundKind::Th => {
+                // voiced in the function words and before "er" inside the word, e.g. "mother"
+                let rest = word.sounds()[index + 1..]
+                    .iter()
+                    .map(|sound| sound.text().to_lowercase())
+                    .collect::<String>();
+
+                match VOICED_TH_WORDS.contains(&normalized.as_str())
+                    || (index > 0 && rest.starts_with("er"))
+                {
+                    true => "ð".to_string(),
+                    false => "θ".to_string(),
+                }
+            }
+            SoundKind::Ptk => {
+                let next = word
+                    .sounds()
+                    .get(index + 1)
+                    .and_then(|sound| sound.text().to_lowercase().chars().next());
+
+                // final stops are unreleased, not aspirated, e.g. "t" in "cat"
+                let aspiration = match sound.is_final() {
+                    true => "",
+                    false => "ʰ",
+                };
+
+                match text.chars().next() {
+                    Some('c') if next.is_some_and(|c| SOFT_C_BEFORE.contains(&c)) => {
+                        "s".to_string()
+                    }
+                    Some('p') => format!("p{}", aspiration),
+                    Some('t') => format!("t{}", aspiration),
+                    _ => format!("k{}", aspiration),
+                }
+            }
+            SoundKind::W => "w".to_string(),
+            SoundKind::V => "v".to_string(),
+            SoundKind::Ng => match text.ends_with('k') {
+                true => "ŋk".to_string(),
+                false => "ŋ".to_string(),
+            },
+            SoundKind::Ch => {
+                // "t" of "tch" is a part of the sound, e.g. "watch"
+                if units.last() == Some(&Unit::Letter('t')) {
+                    units.pop();
+                }
+
+                "tʃ".to_string()
+            }
+            SoundKind::Dj => {
+                if units.last() == Some(&Unit::Letter('d')) {
+                    units.pop();
+                }
+
+                "dʒ".to_string()
+            }
+            SoundKind::Flap => "ɾ".to_string(),
+            SoundKind::GlottalStop => "ʔ".to_string(),
+            SoundKind::Ich => "ç".to_string(),
+            SoundKind::Ach => "x".to_string(),
+            SoundKind::Devoiced => match text.chars().next() {
+                Some('b') => "p".to_string(),
+                Some('d') => "t".to_string(),
+                Some('g') => "k".to_string(),
+                Some('v') => "f".to_string(),
+                _ => "s".to_string(),
+            },
+            SoundKind::B => "b".to_string(),
+            SoundKind::R => "ɹ".to_string(),
+            SoundKind::L => "l".to_string(),
+        };
+
+        units.push(Unit::Sound(unit));
+    }
+
+    Some(spell(&units, dialect))
+}
+
+/// Returns IPA of the units: letters are approximated by the spelling patterns of the dialect
+fn spell(units: &[Unit], dialect: Dialect) -> String {
+    let is_vowel_at = |index: usize| matches!(units.get(index), Some(Unit::Letter(c)) if VOWEL_LETTERS.contains(c));
+    let is_letter_at = |index: usize, letter: char| units.get(index) == Some(&Unit::Letter(letter));
+
+    // "r" before a vowel starts the next syllable, also doubled, e.g. "very" and "Jerry"
+    let is_r_before_vowel = |index: usize| match is_letter_at(index + 1, 'r') {
+        true => is_vowel_at(index + 2),
+        false => is_vowel_at(index + 1),
+    };
+
+    let len = units.len();
+
+    // silent final "e" after a consonant, it makes the vowel before the consonant long, e.g. "make"
+    let is_silent_e = len >= 3
+        && units[len - 1] == Unit::Letter('e')
+        && !is_vowel_at(len - 2)
+        && (0..len - 2).any(is_vowel_at);
+
+    let long_vowel =
+        match is_silent_e && is_vowel_at(len - 3) && !(len >= 4 && is_vowel_at(len - 4)) {
+            true => Some(len - 3),
+            false => None,
+        };
+
+    // the only vowel is the final "e" after a consonant, it is long, e.g. "we" and "be"
+    let is_long_final_e =
+        len >= 2 && units[len - 1] == Unit::Letter('e') && !(0..len - 1).any(is_vowel_at);
+
+    let (r_groups, is_rhotic) = match dialect {
+        Dialect::GeneralAmerican => (&RHOTIC_GROUPS, true),
+        Dialect::ReceivedPronunciation => (&NON_RHOTIC_GROUPS, false),
+    };
+
+    let end = match is_silent_e {
+        true => len - 1,
+        false => len,
+    };
+
+    let mut result = String::new();
+    let mut index = 0;
+
+    while index < end {
+        let letter = match &units[index] {
+            Unit::Sound(ipa) => {
+                result.push_str(ipa);
+                index += 1;
+
+                continue;
+            }
+            Unit::Letter(letter) => *letter,
+        };
+
+        if index == len - 1 && is_long_final_e {
+            result.push_str("iː");
+            index += 1;
+
+            continue;
+        }
+
+        if Some(index) == long_vowel {
+            if let Some((_, ipa)) = LONG_VOWELS.iter().find(|(vowel, _)| *vowel == letter) {
+                result.push_str(ipa);
+                index += 1;
+
+                continue;
+            }
+        }
+
+        // doubled consonants are one sound, e.g. "ll" in "hello"
+        if index > 0 && units[index - 1] == Unit::Letter(letter) && !is_vowel_at(index) {
+            index += 1;
+
+            continue;
+        }
+
+        let letters = units[index..len]
+            .iter()
+            .map_while(|unit| match unit {
+                Unit::Letter(letter) => Some(*letter),
+                Unit::Sound(_) => None,
+            })
+            .collect::<String>();
+
+        let is_group = |group: &str| {
+            letters.starts_with(group)
+                && !(group.ends_with('r') && is_r_before_vowel(index + group.chars().count() - 1))
+        };
+
+        let group = LETTER_GROUPS
+            .iter()
+            .chain(r_groups.iter())
+            .find(|(group, _)| is_group(group));
+
+        if let Some((group, ipa)) = group {
+            result.push_str(ipa);
+            index += group.chars().count();
+
+            continue;
+        }
+
+        // initial "y" before a vowel is a consonant, e.g. "yes"
+        match (letter, index == 0 && is_vowel_at(index + 1)) {
+            ('y', true) => result.push('j'),
+            // "r" is silent in the non-rhotic dialect unless a vowel follows, e.g. "fourth"
+            ('r', _) if !is_rhotic && !is_r_before_vowel(index) => (),
+            ('c', _) if SOFT_C_BEFORE.iter().any(|c| is_letter_at(index + 1, *c)) => {
+                result.push('s')
+            }
+            _ => {
+                if let Some((_, ipa)) = LETTERS.iter().find(|(c, _)| *c == letter) {
+                    result.push_str(ipa);
+                }
+            }
+        }
+
+        index += 1;
+    }
+
+    result
+}
+
+/// Escapes the special characters of XML
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod ssml {
+    use super::ssml;
+
+    fn body(text: &str) -> String {
+        let ssml = ssml(text);
+        let start = ssml.find('>').unwrap() + 1;
+
+        ssml[start..ssml.len() - "</speak>".len()].to_string()
+    }
+
+    #[test]
+    fn it_should_wrap_words_with_defined_sounds() {
+        assert_eq!(
+            body("The cat"),
+            "<phoneme alphabet=\"ipa\" ph=\"ðə\">The</phoneme> <phoneme alphabet=\"ipa\" ph=\"kʰæt\">cat</phoneme>"
+        );
+        assert_eq!(body("a bed"), "a bed");
+    }
+
+    #[test]
+    fn it_should_approximate_spelling() {
+        assert_eq!(body("make"), "make");
+        assert_eq!(
+            body("take"),
+            "<phoneme alphabet=\"ipa\" ph=\"tʰeɪk\">take</phoneme>"
+        );
+        assert_eq!(
+            body("watch"),
+            "<phoneme alphabet=\"ipa\" ph=\"wætʃ\">watch</phoneme>"
+        );
+        assert_eq!(
+            body("butter"),
+            "<phoneme alphabet=\"ipa\" ph=\"bʌɾɚ\">butter</phoneme>"
+        );
+        assert_eq!(
+            body("mother"),
+            "<phoneme alphabet=\"ipa\" ph=\"mɑðɚ\">mother</phoneme>"
+        );
+    }
+
+    #[test]
+    fn it_should_aspirate_stops_unless_final() {
+        assert_eq!(
+            body("put"),
+            "<phoneme alphabet=\"ipa\" ph=\"pʰʌt\">put</phoneme>"
+        );
+        assert_eq!(
+            body("cat"),
+            "<phoneme alphabet=\"ipa\" ph=\"kʰæt\">cat</phoneme>"
+        );
+    }
+
+    #[test]
+    fn it_should_transcribe_soft_c_and_doubled_r() {
+        assert_eq!(
+            body("city"),
+            "<phoneme alphabet=\"ipa\" ph=\"sɪɾi\">city</phoneme>"
+        );
+        assert_eq!(
+            body("Jerry"),
+            "<phoneme alphabet=\"ipa\" ph=\"dʒɛɹi\">Jerry</phoneme>"
+        );
+    }
+
+    #[test]
+    fn it_should_leave_unreliable_spelling_to_engine() {
+        assert_eq!(body("TV"), "TV");
+        assert_eq!(body("café"), "café");
+    }
+
+    #[test]
+    fn it_should_escape_xml() {
+        assert_eq!(
+            body("Sam & Al <3 \"no\" 'mm'"),
+            "Sam &amp; Al &lt;3 &quot;no&quot; &apos;mm&apos;"
+        );
+        assert_eq!(
+            body("Sam's path"),
+            "Sam&apos;s <phoneme alphabet=\"ipa\" ph=\"pʰæθ\">path</phoneme>"
+        );
+    }
+
+    #[test]
+    fn it_should_render_empty_text() {
+        assert_eq!(body(""), "");
+    }
+}
+
+#[cfg(test)]
+mod ssml_with {
+    use super::{ssml_with, Options, SsmlOptions};
+    use crate::dialect::Dialect;
+    use crate::sound::SoundKind;
+
+    #[test]
+    fn it_should_emphasize_target_sounds() {
+        let ssml_options = SsmlOptions {
+            emphasis: vec![SoundKind::Th],
+            ..SsmlOptions::default()
+        };
+
+        let ssml = ssml_with("Think big", &ssml_options, &Options::default());
+
+        assert!(ssml.contains("<emphasis level=\"moderate\"><phoneme alphabet=\"ipa\" ph=\"θɪŋk\">Think</phoneme></emphasis> "));
+        assert!(ssml.ends_with(" big</speak>"));
+    }
+
+    #[test]
+    fn it_should_break_sentences() {
+        let ssml_options = SsmlOptions {
+            sentence_break: Some(500),
+            ..SsmlOptions::default()
+        };
+
+        let ssml = ssml_with("Hi. Bye!\n\nOk?", &ssml_options, &Options::default());
+
+        assert_eq!(ssml.matches("<break time=\"500ms\"/>").count(), 2);
+        assert!(ssml.ends_with("Ok?</speak>"));
+    }
+
+    #[test]
+    fn it_should_render_with_options() {
+        let options = Options {
+            dialect: Dialect::ReceivedPronunciation,
+            expand: true,
+            ..Options::default()
+        };
+
+        let ssml = ssml_with("The 3rd", &SsmlOptions::default(), &options);
+
+        assert!(ssml.contains("xml:lang=\"en-GB\""));
+        assert!(ssml.contains("ph=\"θɜːd\">3rd</phoneme>"));
+    }
+
+    #[test]
+    fn it_should_follow_rhoticity_of_dialect() {
+        let options = Options {
+            dialect: Dialect::ReceivedPronunciation,
+            ..Options::default()
+        };
+
+        let general_american =
+            ssml_with("third mother", &SsmlOptions::default(), &Options::default());
+        let received_pronunciation = ssml_with("third mother", &SsmlOptions::default(), &options);
+
+        assert!(general_american.contains("ph=\"θɝd\""));
+        assert!(general_american.contains("ph=\"mɑðɚ\""));
+        assert!(received_pronunciation.contains("ph=\"θɜːd\""));
+        assert!(received_pronunciation.contains("ph=\"mɑðə\""));
+        assert!(ssml_with("Jerry", &SsmlOptions::default(), &options).contains("ph=\"dʒɛɹi\""));
+    }
+}
@@ -0,0 +1,595 @@
+use crate::tokenizer::{tokenize, Token};
+use std::ops::Range;
+
+/// Words of the numbers below twenty
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+/// Words of the tens
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// Words of the scales with their values
+const SCALES: [(u64, &str); 3] = [
+    (1_000_000_000, "billion"),
+    (1_000_000, "million"),
+    (1_000, "thousand"),
+];
+
+/// Irregular ordinal words
+const IRREGULAR_ORDINALS: [(&str, &str); 7] = [
+    ("one", "first"),
+    ("two", "second"),
+    ("three", "third"),
+    ("five", "fifth"),
+    ("eight", "eighth"),
+    ("nine", "ninth"),
+    ("twelve", "twelfth"),
+];
+
+/// Ordinal suffixes after digits
+const ORDINAL_SUFFIXES: [&str; 4] = ["st", "nd", "rd", "th"];
+
+/// Currency symbols with the words of the unit and the subunit (singular, plural)
+const CURRENCIES: [(&str, [&str; 4]); 3] = [
+    ("$", ["dollar", "dollars", "cent", "cents"]),
+    ("€", ["euro", "euros", "cent", "cents"]),
+    ("£", ["pound", "pounds", "penny", "pence"]),
+];
+
+/// Symbols that are spoken as words
+const SYMBOLS: [(&str, &str); 5] = [
+    ("&", "and"),
+    ("%", "percent"),
+    ("+", "plus"),
+    ("=", "equals"),
+    ("@", "at"),
+];
+
+/// Common abbreviations that are followed by a full stop
+const ABBREVIATIONS: [(&str, &str); 13] = [
+    ("mr", "mister"),
+    ("mrs", "missus"),
+    ("dr", "doctor"),
+    ("prof", "professor"),
+    ("mt", "mount"),
+    ("jr", "junior"),
+    ("sr", "senior"),
+    ("vs", "versus"),
+    ("etc", "et cetera"),
+    ("approx", "approximately"),
+    ("dept", "department"),
+    ("e.g", "for example"),
+    ("i.e", "that is"),
+];
+
+/// Abbreviation spoken as "saint" before a name, e.g. "St. Paul", and as "street" otherwise, e.g. "Main St."
+const SAINT_OR_STREET: &str = "st";
+
+/// Numbers with more digits are spoken digit by digit
+const MAX_CARDINAL_DIGITS: usize = 12;
+
+/// Part of the source text that is spoken as other words
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expansion {
+    span: Range<usize>,
+    normalized_span: Range<usize>,
+    text: String,
+}
+
+impl Expansion {
+    /// Returns byte span of the expansion in the source text
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Returns byte span of the expansion in the normalized text
+    pub fn normalized_span(&self) -> Range<usize> {
+        self.normalized_span.clone()
+    }
+
+    /// Returns spoken words of the expansion
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// Normalized text with the mapping back to the source text
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NormalizedText {
+    text: String,
+    expansions: Vec<Expansion>,
+}
+
+impl NormalizedText {
+    /// Returns normalized text
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns expansions in order of the source text
+    pub fn expansions(&self) -> &[Expansion] {
+        &self.expansions
+    }
+
+    /// Maps byte span of the normalized text back to the span of the source text.
+    /// A span inside an expansion is widened to the whole source of the expansion.
+    pub fn source_span(&self, span: Range<usize>) -> Range<usize> {
+        self.source_offset(span.start, false)..self.source_offset(span.end, true)
+    }
+
+    fn source_offset(&self, offset: usize, is_end: bool) -> usize {
+        let mut source_offset = offset;
+
+        for expansion in &self.expansions {
+            let normalized = expansion.normalized_span();
+
+            if offset < normalized.start || (offset == normalized.start && is_end) {
+                break;
+            }
+
+            if offset < normalized.end || (offset == normalized.end && is_end) {
+                return match is_end {
+                    true => expansion.span.end,
+                    false => expansion.span.start,
+                };
+            }
+
+            source_offset = source_offset + expansion.span.len() - normalized.len();
+        }
+
+        source_offset
+    }
+}
+
+/// Expand numbers, ordinals, currencies, times, symbols and common abbreviations into words
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::normalize_text;
+///
+/// let normalized = normalize_text("Dr. Smith paid $5 on the 3rd at 10:30");
+///
+/// assert_eq!(normalized.text(), "Doctor Smith paid five dollars on the third at ten thirty");
+/// assert_eq!(normalized.source_span(18..30), 15..17);
+/// ```
+pub fn normalize_text(text: &str) -> NormalizedText {
+    let tokens = tokenize(text);
+
+    let mut normalized = String::new();
+    let mut expansions = vec![];
+
+    let mut index = 0;
+
+    while index < tokens.len() {
+        match expand(&tokens[index..]) {
+            Some((count, spoken)) => {
+                let span = tokens[index].span().start..tokens[index + count - 1].span().end;
+                let spoken = match text[span.clone()].starts_with(char::is_uppercase) {
+                    true => capitalize(&spoken),
+                    false => spoken,
+                };
+
+                expansions.push(Expansion {
+                    span,
+                    normalized_span: normalized.len()..normalized.len() + spoken.len(),
+                    text: spoken.clone(),
+                });
+
+                normalized.push_str(&spoken);
+                index += count;
+            }
+            None => {
+                let count = count_kept(&tokens[index..]);
+
+                for token in &tokens[index..index + count] {
+                    normalized.push_str(token.text());
+                }
+
+                index += count;
+            }
+        }
+    }
+
+    NormalizedText {
+        text: normalized,
+        expansions,
+    }
+}
+
+/// Returns count of the tokens and their spoken words if the tokens should be expanded
+fn expand(tokens: &[Token]) -> Option<(usize, String)> {
+    let text = |index: usize| tokens.get(index).map(Token::text).unwrap_or("");
+
+    if let Some((_, words)) = CURRENCIES.iter().find(|(symbol, _)| *symbol == text(0)) {
+        if let Some(spoken) = currency(text(1), words) {
+            return Some((2, spoken));
+        }
+    }
+
+    // invalid times like "25:61" are not expanded at all, see `count_kept`
+    if text(1) == ":" && is_time_like(text(0), text(2)) {
+        return time(text(0), text(2)).map(|spoken| (3, spoken));
+    }
+
+    if let Some(spoken) = number(text(0)) {
+        return match text(1) == "%" {
+            true => Some((2, spoken + " percent")),
+            false => Some((1, spoken)),
+        };
+    }
+
+    if let Some(spoken) = ordinal(text(0)) {
+        return Some((1, spoken));
+    }
+
+    if let Some((_, spoken)) = SYMBOLS.iter().find(|(symbol, _)| *symbol == text(0)) {
+        return Some((1, spoken.to_string()));
+    }
+
+    if text(1) == "." {
+        let word = text(0).to_lowercase();
+
+        if let Some((_, spoken)) = ABBREVIATIONS.iter().find(|(short, _)| *short == word) {
+            return Some((2, spoken.to_string()));
+        }
+
+        if word == SAINT_OR_STREET {
+            let is_name_next = tokens[2..]
+                .iter()
+                .find(|token| !token.text().trim().is_empty())
+                .is_some_and(|token| {
+                    token.is_word() && token.text().starts_with(char::is_uppercase)
+                });
+
+            return match is_name_next {
+                true => Some((2, String::from("saint"))),
+                false => Some((2, String::from("street"))),
+            };
+        }
+    }
+
+    None
+}
+
+/// Returns count of the tokens that are kept as they are, e.g. all the tokens of the invalid time "25:61"
+fn count_kept(tokens: &[Token]) -> usize {
+    let text = |index: usize| tokens.get(index).map(Token::text).unwrap_or("");
+
+    match text(1) == ":" && is_time_like(text(0), text(2)) {
+        true => 3,
+        false => 1,
+    }
+}
+
+/// Returns true if the text is an integer, digit groups may be separated by commas
+fn is_integer(text: &str) -> bool {
+    let mut groups = text.split(',');
+
+    let first = groups.next().unwrap_or("");
+    let is_first_valid = !first.is_empty()
+        && first.chars().all(|c| c.is_ascii_digit())
+        && (first.len() <= 3 || !text.contains(','));
+
+    is_first_valid
+        && groups.all(|group| group.len() == 3 && group.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Returns spoken words of the integer or the decimal number
+fn number(text: &str) -> Option<String> {
+    let (integer, fraction) = text.split_once('.').unwrap_or((text, ""));
+
+    if !is_integer(integer) || !fraction.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    if text.contains('.') && fraction.is_empty() {
+        return None;
+    }
+
+    let digits = integer.replace(',', "");
+
+    let mut spoken = match digits.len() > MAX_CARDINAL_DIGITS {
+        true => spell_digits(&digits),
+        false => cardinal(digits.parse().ok()?),
+    };
+
+    if !fraction.is_empty() {
+        spoken = spoken + " point " + &spell_digits(fraction);
+    }
+
+    Some(spoken)
+}
+
+/// Returns spoken words of the ordinal number like "3rd"
+fn ordinal(text: &str) -> Option<String> {
+    let split_at = text.find(|c: char| !c.is_ascii_digit())?;
+    let (digits, suffix) = text.split_at(split_at);
+
+    if digits.is_empty()
+        || digits.len() > MAX_CARDINAL_DIGITS
+        || suffix.to_lowercase() != ordinal_suffix(digits)
+    {
+        return None;
+    }
+
+    Some(to_ordinal(&cardinal(digits.parse().ok()?)))
+}
+
+/// Returns ordinal suffix of the number: "st" after 1, "nd" after 2, "rd" after 3 and "th" after 11–13 and others
+fn ordinal_suffix(digits: &str) -> &'static str {
+    let last_two = &digits[digits.len().saturating_sub(2)..];
+
+    match (last_two, digits.chars().last()) {
+        ("11" | "12" | "13", _) => ORDINAL_SUFFIXES[3],
+        (_, Some('1')) => ORDINAL_SUFFIXES[0],
+        (_, Some('2')) => ORDINAL_SUFFIXES[1],
+        (_, Some('3')) => ORDINAL_SUFFIXES[2],
+        _ => ORDINAL_SUFFIXES[3],
+    }
+}
+
+/// Returns spoken words of the amount of money
+fn currency(amount: &str, words: &[&str; 4]) -> Option<String> {
+    let (integer, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+
+    if !is_integer(integer) || integer.len() > MAX_CARDINAL_DIGITS {
+        return None;
+    }
+
+    let units = integer.replace(',', "").parse::<u64>().ok()?;
+
+    let subunits = match fraction.len() {
+        0 if !amount.contains('.') => 0,
+        2 => fraction.parse::<u64>().ok()?,
+        _ => return None,
+    };
+
+    let with_unit = |value: u64, singular: &str, plural: &str| {
+        let unit = if value == 1 { singular } else { plural };
+
+        cardinal(value) + " " + unit
+    };
+
+    Some(match (units, subunits) {
+        (0, subunits) if subunits > 0 => with_unit(subunits, words[2], words[3]),
+        (units, 0) => with_unit(units, words[0], words[1]),
+        (units, subunits) => {
+            with_unit(units, words[0], words[1])
+                + " and "
+                + &with_unit(subunits, words[2], words[3])
+        }
+    })
+}
+
+/// Returns true if the hours and the minutes are written as a time, valid or not, e.g. "10:30" or "25:61"
+fn is_time_like(hours: &str, minutes: &str) -> bool {
+    let is_digits = |text: &str| !text.is_empty() && text.chars().all(|c| c.is_ascii_digit());
+
+    is_digits(hours) && hours.len() <= 2 && is_digits(minutes) && minutes.len() == 2
+}
+
+/// Returns spoken words of the time like "10:30"
+fn time(hours: &str, minutes: &str) -> Option<String> {
+    let hours = hours.parse::<u64>().ok()?;
+    let minutes = minutes.parse::<u64>().ok()?;
+
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+
+    Some(match minutes {
+        0 => cardinal(hours) + " o'clock",
+        1..=9 => cardinal(hours) + " oh " + &cardinal(minutes),
+        _ => cardinal(hours) + " " + &cardinal(minutes),
+    })
+}
+
+/// Returns spoken words of the cardinal number
+fn cardinal(number: u64) -> String {
+    if number < 20 {
+        return ONES[number as usize].to_string();
+    }
+
+    if number < 100 {
+        let tens = TENS[(number / 10) as usize].to_string();
+
+        return match number % 10 {
+            0 => tens,
+            ones => tens + "-" + ONES[ones as usize],
+        };
+    }
+
+    if number < 1_000 {
+        let hundreds = cardinal(number / 100) + " hundred";
+
+        return match number % 100 {
+            0 => hundreds,
+            rest => hundreds + " " + &cardinal(rest),
+        };
+    }
+
+    let (scale, name) = SCALES
+        .iter()
+        .find(|(scale, _)| number >= *scale)
+        .copied()
+        .unwrap_or(SCALES[SCALES.len() - 1]);
+
+    let spoken = cardinal(number / scale) + " " + name;
+
+    match number % scale {
+        0 => spoken,
+        rest => spoken + " " + &cardinal(rest),
+    }
+}
+
+/// Turns the last word of the cardinal number to the ordinal one
+fn to_ordinal(cardinal: &str) -> String {
+    let split_at = cardinal
+        .rfind([' ', '-'])
+        .map(|index| index + 1)
+        .unwrap_or(0);
+    let (head, last) = cardinal.split_at(split_at);
+
+    let last = match IRREGULAR_ORDINALS.iter().find(|(word, _)| *word == last) {
+        Some((_, ordinal)) => ordinal.to_string(),
+        None => match last.strip_suffix('y') {
+            Some(stem) => stem.to_string() + "ieth",
+            None => last.to_string() + "th",
+        },
+    };
+
+    head.to_string() + &last
+}
+
+/// Returns spoken words of the digits one by one
+fn spell_digits(digits: &str) -> String {
+    digits
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .map(|digit| ONES[digit as usize])
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+/// Returns text with the first letter in uppercase
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod normalize_text {
+    use super::normalize_text;
+
+    fn normalized(text: &str) -> String {
+        normalize_text(text).text().to_string()
+    }
+
+    #[test]
+    fn it_should_normalize_empty() {
+        assert_eq!(normalized(""), "");
+    }
+
+    #[test]
+    fn it_should_keep_words() {
+        assert_eq!(normalized("Put it there."), "Put it there.");
+    }
+
+    #[test]
+    fn it_should_normalize_numbers() {
+        assert_eq!(
+            normalized("0 7 13 40 99"),
+            "zero seven thirteen forty ninety-nine"
+        );
+        assert_eq!(normalized("1,234"), "one thousand two hundred thirty-four");
+        assert_eq!(normalized("2000000"), "two million");
+        assert_eq!(normalized("3.14"), "three point one four");
+        assert_eq!(normalized("5%"), "five percent");
+    }
+
+    #[test]
+    fn it_should_normalize_ordinals() {
+        assert_eq!(
+            normalized("1st 2nd 3rd 5th 9th 12th 20th 21st 100th"),
+            "first second third fifth ninth twelfth twentieth twenty-first one hundredth"
+        );
+        assert_eq!(
+            normalized("11th 12th 13th 111th 22nd 103rd"),
+            "eleventh twelfth thirteenth one hundred eleventh twenty-second one hundred third"
+        );
+        assert_eq!(normalized("1th 2st 11st 13rd"), "1th 2st 11st 13rd");
+    }
+
+    #[test]
+    fn it_should_normalize_currencies() {
+        assert_eq!(normalized("$5"), "five dollars");
+        assert_eq!(normalized("$1"), "one dollar");
+        assert_eq!(normalized("$5.99"), "five dollars and ninety-nine cents");
+        assert_eq!(normalized("$0.50"), "fifty cents");
+        assert_eq!(normalized("£2"), "two pounds");
+        assert_eq!(normalized("€1.01"), "one euro and one cent");
+    }
+
+    #[test]
+    fn it_should_normalize_times() {
+        assert_eq!(normalized("10:30"), "ten thirty");
+        assert_eq!(normalized("7:00"), "seven o'clock");
+        assert_eq!(normalized("9:05"), "nine oh five");
+        assert_eq!(normalized("25:61"), "25:61");
+        assert_eq!(normalized("at 24:00 or 9:60"), "at 24:00 or 9:60");
+    }
+
+    #[test]
+    fn it_should_normalize_symbols() {
+        assert_eq!(normalized("Tom & Jerry"), "Tom and Jerry");
+        assert_eq!(normalized("2 + 2 = 4"), "two plus two equals four");
+    }
+
+    #[test]
+    fn it_should_normalize_abbreviations() {
+        assert_eq!(normalized("Dr. Smith"), "Doctor Smith");
+        assert_eq!(normalized("Mrs. Jones"), "Missus Jones");
+        assert_eq!(
+            normalized("apples, e.g. green"),
+            "apples, for example green"
+        );
+        assert_eq!(normalized("Dr Smith"), "Dr Smith");
+        assert_eq!(normalized("St. Paul"), "Saint Paul");
+        assert_eq!(normalized("Main St. is long"), "Main Street is long");
+    }
+
+    #[test]
+    fn it_should_map_back_to_source_spans() {
+        let normalized = normalize_text("Dr. Who at 10:30 paid $5");
+
+        assert_eq!(
+            normalized.text(),
+            "Doctor Who at ten thirty paid five dollars"
+        );
+
+        assert_eq!(normalized.source_span(0..6), 0..3);
+        assert_eq!(normalized.source_span(7..10), 4..7);
+        assert_eq!(normalized.source_span(14..17), 11..16);
+        assert_eq!(normalized.source_span(18..24), 11..16);
+        assert_eq!(normalized.source_span(25..29), 17..21);
+        assert_eq!(normalized.source_span(30..42), 22..24);
+    }
+
+    #[test]
+    fn it_should_keep_expansions() {
+        let normalized = normalize_text("the 3rd");
+
+        let expansion = &normalized.expansions()[0];
+
+        assert_eq!(expansion.span(), 4..7);
+        assert_eq!(expansion.normalized_span(), 4..9);
+        assert_eq!(expansion.text(), "third");
+    }
+}
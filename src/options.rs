@@ -0,0 +1,47 @@
+use crate::dialect::Dialect;
+use crate::lexicon::Lexicon;
+use crate::profile::Profile;
+use crate::rules::RuleSet;
+
+/// Options of the parsing and highlighting
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::{parse_with, Options};
+///
+/// let options = Options {
+///     nfc: true,
+///     ..Options::default()
+/// };
+///
+/// assert_eq!(parse_with("cafe\u{301}", &options)[3].text(), "\u{e9}");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    /// Apply NFC normalization to the text before scanning
+    pub nfc: bool,
+    /// Expand numbers, ordinals, currencies, times, symbols and common abbreviations into words
+    pub expand: bool,
+    /// User-defined pronunciations that take priority over the rules
+    pub lexicon: Lexicon,
+    /// Rules of the sounds instead of the bundled rules of the dialect
+    pub rules: Option<RuleSet>,
+    /// English accent with its own rules and lexicon, General American by default
+    pub dialect: Dialect,
+    /// Highlight sound changes across word boundaries of the connected speech, e.g. "did you"
+    pub connected_speech: bool,
+    /// Difficulty profile of the learners: only its sounds are highlighted and its rules go first
+    pub profile: Option<Profile>,
+    /// Group rhyming words in spans of the `Rhyme` class with the number of the group, e.g. `Rhyme1`
+    pub rhymes: bool,
+    /// Recognized speech of the learner, its pronunciation errors against the text are highlighted
+    pub recognized: Option<String>,
+}
+
+impl Options {
+    /// Returns the rules of the sounds
+    pub fn rules(&self) -> &RuleSet {
+        self.rules.as_ref().unwrap_or_else(|| self.dialect.rules())
+    }
+}
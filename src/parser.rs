@@ -1,17 +1,13 @@
+use crate::acronym::{is_capitals_text, parse_initialism};
+use crate::connected::{connect, Link};
+use crate::language::{English, Language};
+use crate::options::Options;
+use crate::rules::parse_word_by;
 use crate::scanner::Scanner;
+use crate::segmenter::{segment, Paragraph};
 use crate::sound::{Sound, SoundKind};
-
-fn add_sound_from_two_letters(
-    first_letter: &char,
-    second_letter: &char,
-    kind: SoundKind,
-    sounds: &mut Vec<Sound>,
-) {
-    sounds.push(Sound::new(
-        kind,
-        first_letter.to_string() + &String::from(*second_letter),
-    ));
-}
+use crate::tokenizer::{tokenize_with, Token, TokenKind};
+use crate::word::Word;
 
 /// Parse text to sounds
 ///
@@ -46,91 +42,272 @@ fn add_sound_from_two_letters(
 /// assert_eq!(parse("The text just in case"), sounds);
 /// ```
 pub fn parse<T: AsRef<str>>(text: T) -> Vec<Sound> {
-    let text = text.as_ref();
+    parse_with(text, &Options::default())
+}
 
-    let mut sounds = vec![];
+/// Parse text to sounds with the options
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::{parse_with, Options, Sound, SoundKind};
+///
+/// let sounds = vec![
+///     Sound::new(SoundKind::Ptk, String::from("c")),
+///     Sound::new(SoundKind::Undefined, String::from("a")),
+///     Sound::new(SoundKind::Undefined, String::from("f")),
+///     Sound::new(SoundKind::Undefined, String::from("\u{e9}")),
+/// ];
+///
+/// let options = Options {
+///     nfc: true,
+///     ..Options::default()
+/// };
+///
+/// assert_eq!(parse_with("cafe\u{301}", &options), sounds);
+/// ```
+pub fn parse_with<T: AsRef<str>>(text: T, options: &Options) -> Vec<Sound> {
+    parse_in(text, &English, options)
+}
 
-    if text.is_empty() {
-        return sounds;
-    }
+/// Parse text of the language to sounds with the options
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::{parse_in, German, Options, SoundKind};
+///
+/// let sounds = parse_in("Hund", &German, &Options::default());
+///
+/// assert_eq!(sounds[3].kind(), &SoundKind::Devoiced);
+/// ```
+pub fn parse_in<T: AsRef<str>, L: Language>(
+    text: T,
+    language: &L,
+    options: &Options,
+) -> Vec<Sound> {
+    parse_words_in(text, language, options)
+        .into_iter()
+        .flat_map(Word::into_sounds)
+        .collect()
+}
 
-    let text_splited = text.split(' ').collect::<Vec<&str>>();
+/// Parse text to words with their sounds, the separators between words are kept
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::{parse_words, serialize, SoundKind};
+///
+/// let words = parse_words("Think, then");
+///
+/// assert_eq!(words[0].text(), "Think");
+/// assert_eq!(words[0].normalized(), "think");
+/// assert_eq!(words[0].sounds()[0].kind(), &SoundKind::Th);
+/// assert!(words[1].is_separator());
+/// assert_eq!(words[3].span(), 7..11);
+///
+/// assert_eq!(serialize(words), "Think, then");
+/// ```
+pub fn parse_words<T: AsRef<str>>(text: T) -> Vec<Word> {
+    parse_words_with(text, &Options::default())
+}
 
-    for (index, word) in text_splited.iter().enumerate() {
-        let mut scanner = Scanner::new(word);
+/// Parse text to words with their sounds and the options
+pub fn parse_words_with<T: AsRef<str>>(text: T, options: &Options) -> Vec<Word> {
+    parse_words_in(text, &English, options)
+}
 
-        while !scanner.is_done() {
-            match scanner.peek() {
-                letter @ ('c' | 'C')
-                    if !scanner.is_last() && scanner.is_next_any(vec!['h', 'H']) =>
-                {
-                    let next_letter = scanner.peek_next();
+/// Parse text of the language to words with their sounds and the options
+pub fn parse_words_in<T: AsRef<str>, L: Language>(
+    text: T,
+    language: &L,
+    options: &Options,
+) -> Vec<Word> {
+    let text = text.as_ref();
 
-                    add_sound_from_two_letters(letter, next_letter, SoundKind::Ch, &mut sounds);
+    let normalized = language.normalize(text, options);
 
-                    scanner.pop();
-                    scanner.pop();
-                }
-                letter @ ('p' | 'P' | 't' | 'T' | 'c' | 'C')
-                    if scanner.is_first() || scanner.is_last() =>
-                {
-                    if (letter == &'t' || letter == &'T')
-                        && !scanner.is_last()
-                        && scanner.is_next_any(vec!['h', 'H'])
-                    {
-                        let next_letter = scanner.peek_next();
+    let tokens = tokenize_with(text, language.word_separators());
+    let is_capitals_text = is_capitals_text(&tokens);
 
-                        add_sound_from_two_letters(letter, next_letter, SoundKind::Th, &mut sounds);
+    let mut expansions = normalized.expansions().iter().peekable();
+    let mut words = vec![];
 
-                        scanner.pop();
-                        scanner.pop();
+    for (index, token) in tokens.iter().enumerate() {
+        if let Some(&expansion) = expansions.peek() {
+            if token.span().start >= expansion.span().start {
+                if token.span().end == expansion.span().end {
+                    let spoken = tokenize_with(expansion.text(), language.word_separators());
 
-                        continue;
-                    }
+                    let sounds = spoken
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(index, token)| {
+                            let next_word = next_word(&spoken, index);
 
-                    sounds.push(Sound::new(SoundKind::Ptk, letter.to_string()));
-                    scanner.pop();
-                }
-                letter @ ('t' | 'T') if scanner.is_next_any(vec!['h', 'H']) => {
-                    let next_letter = scanner.peek_next();
+                            let mut sounds =
+                                parse_token(token, next_word, language, options, false);
 
-                    add_sound_from_two_letters(letter, next_letter, SoundKind::Th, &mut sounds);
+                            if token.is_word() {
+                                mark_final_consonants(&mut sounds);
+                            }
 
-                    scanner.pop();
-                    scanner.pop();
-                }
-                letter @ ('w' | 'W') if scanner.is_first() => {
-                    sounds.push(Sound::new(SoundKind::W, letter.to_string()));
-                    scanner.pop();
-                }
-                letter @ ('v' | 'V') if scanner.is_first() => {
-                    sounds.push(Sound::new(SoundKind::V, letter.to_string()));
-                    scanner.pop();
-                }
-                letter @ ('n' | 'N')
-                    if !scanner.is_last() && scanner.is_next_any(vec!['g', 'G', 'k', 'K']) =>
-                {
-                    let next_letter = scanner.peek_next();
+                            sounds
+                        })
+                        .collect();
 
-                    add_sound_from_two_letters(letter, next_letter, SoundKind::Ng, &mut sounds);
+                    words.push(Word::expanded(
+                        &text[expansion.span()],
+                        expansion.span(),
+                        expansion.text(),
+                        sounds,
+                    ));
 
-                    scanner.pop();
-                    scanner.pop();
-                }
-                letter @ ('j' | 'J') if scanner.is_first() => {
-                    sounds.push(Sound::new(SoundKind::Dj, letter.to_string()));
-                    scanner.pop();
-                }
-                _ => {
-                    let letter = scanner.pop();
-                    sounds.push(Sound::new(SoundKind::Undefined, letter.to_string()));
+                    expansions.next();
                 }
+
+                continue;
             }
         }
 
-        if index + 1 != text_splited.len() {
-            sounds.push(Sound::new(SoundKind::Undefined, " ".to_string()));
+        let next_word = next_word(&tokens, index);
+        let mut sounds = parse_token(token, next_word, language, options, is_capitals_text);
+
+        if token.is_word() {
+            mark_final_consonants(&mut sounds);
+        }
+
+        words.push(Word::new(token.kind(), token.text(), token.span(), sounds));
+    }
+
+    language.disambiguate(&mut words);
+
+    words
+}
+
+/// Parse text to paragraphs of sentences of words
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::parse_paragraphs;
+///
+/// let paragraphs = parse_paragraphs("Dr. Smith is here. Thanks!\n\nBye");
+///
+/// let sentences = paragraphs[0]
+///     .sentences()
+///     .iter()
+///     .map(|sentence| sentence.text())
+///     .collect::<Vec<&str>>();
+///
+/// assert_eq!(paragraphs.len(), 2);
+/// assert_eq!(sentences, vec!["Dr. Smith is here. ", "Thanks!\n\n"]);
+/// ```
+pub fn parse_paragraphs<T: AsRef<str>>(text: T) -> Vec<Paragraph> {
+    parse_paragraphs_with(text, &Options::default())
+}
+
+/// Parse text to paragraphs of sentences of words with the options
+pub fn parse_paragraphs_with<T: AsRef<str>>(text: T, options: &Options) -> Vec<Paragraph> {
+    segment(parse_words_with(text, options))
+}
+
+/// Parse text to sound changes across the word boundaries of the connected speech
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::{parse_links, LinkKind, SoundKind};
+///
+/// let links = parse_links("Did you want to go?");
+///
+/// assert_eq!(links[0].kind(), LinkKind::Coalescence);
+/// assert_eq!(links[0].text(), "d y");
+/// assert_eq!(links[0].sound(), SoundKind::Dj);
+/// assert_eq!(links[1].description(), "want to → wanna");
+/// ```
+pub fn parse_links<T: AsRef<str>>(text: T) -> Vec<Link> {
+    parse_links_with(text, &Options::default())
+}
+
+/// Parse text to sound changes across the word boundaries with the options
+pub fn parse_links_with<T: AsRef<str>>(text: T, options: &Options) -> Vec<Link> {
+    let text = text.as_ref();
+
+    connect(text, &parse_words_with(text, options), options)
+}
+
+/// Parse the token to sounds: by the lexicons, as the initialism or by the rules
+fn parse_token<L: Language>(
+    token: &Token,
+    next_word: Option<&str>,
+    language: &L,
+    options: &Options,
+    is_capitals_text: bool,
+) -> Vec<Sound> {
+    let scanner = Scanner::with_options(token.text(), options);
+
+    if token.kind() == TokenKind::Separator {
+        return parse_separator(scanner);
+    }
+
+    if let Some(sounds) = options.lexicon.parse_word(token.text(), scanner) {
+        return sounds;
+    }
+
+    if let Some(lexicon) = language.lexicon(options) {
+        let scanner = Scanner::with_options(token.text(), options);
+
+        if let Some(sounds) = lexicon.parse_word(token.text(), scanner) {
+            return sounds;
+        }
+    }
+
+    let scanner = Scanner::with_options(token.text(), options);
+
+    if !is_capitals_text && language.is_initialism(token.text()) {
+        return parse_initialism(scanner);
+    }
+
+    let scanner = scanner.with_next_word(next_word.unwrap_or_default());
+
+    match &options.profile {
+        Some(profile) => parse_word_by(&[profile.rules(), language.rules(options)], scanner),
+        None => language.rules(options).parse_word(scanner),
+    }
+}
+
+/// Marks the consonants at the end of the word, e.g. "g" and "s" in "dogs"
+fn mark_final_consonants(sounds: &mut [Sound]) {
+    for sound in sounds.iter_mut().rev() {
+        if !sound.is_consonant() {
+            break;
+        }
+
+        sound.set_final();
+    }
+}
+
+/// Returns the word following the token after spaces, words split by punctuation are not linked
+fn next_word<'a>(tokens: &[Token<'a>], index: usize) -> Option<&'a str> {
+    match (tokens.get(index + 1), tokens.get(index + 2)) {
+        (Some(separator), Some(word))
+            if word.is_word() && separator.text().chars().all(|c| c == ' ') =>
+        {
+            Some(word.text())
         }
+        _ => None,
+    }
+}
+
+/// Parse the separator graphemes to undefined sounds
+fn parse_separator(mut scanner: Scanner) -> Vec<Sound> {
+    let mut sounds = vec![];
+
+    while !scanner.is_done() {
+        let grapheme = scanner.pop();
+        sounds.push(Sound::new(SoundKind::Undefined, grapheme.to_string()));
     }
 
     sounds
@@ -194,8 +371,7 @@ mod parse {
             Sound::new(SoundKind::Th, String::from("th")),
             Sound::new(SoundKind::Undefined, String::from("r")),
             Sound::new(SoundKind::Undefined, String::from("o")),
-            Sound::new(SoundKind::Undefined, String::from("t")),
-            Sound::new(SoundKind::Undefined, String::from("t")),
+            Sound::new(SoundKind::Flap, String::from("tt")),
             Sound::new(SoundKind::Undefined, String::from("l")),
             Sound::new(SoundKind::Undefined, String::from("e")),
         ];
@@ -401,4 +577,659 @@ mod parse {
 
         assert_eq!(parse("PinK briNging something to KiNG to driNk"), sounds);
     }
+
+    #[test]
+    fn it_should_parse_words_separated_by_newlines_and_quotes() {
+        let sounds = vec![
+            Sound::new(SoundKind::Undefined, String::from("\"")),
+            Sound::new(SoundKind::Ptk, String::from("P")),
+            Sound::new(SoundKind::Undefined, String::from("u")),
+            Sound::new(SoundKind::Ptk, String::from("t")),
+            Sound::new(SoundKind::Undefined, String::from("\"")),
+            Sound::new(SoundKind::Undefined, String::from("\n")),
+            Sound::new(SoundKind::Undefined, String::from("(")),
+            Sound::new(SoundKind::Th, String::from("th")),
+            Sound::new(SoundKind::Undefined, String::from("e")),
+            Sound::new(SoundKind::Undefined, String::from(")")),
+            Sound::new(SoundKind::Undefined, String::from("—")),
+            Sound::new(SoundKind::V, String::from("v")),
+            Sound::new(SoundKind::Undefined, String::from("e")),
+            Sound::new(SoundKind::Ptk, String::from("t")),
+        ];
+
+        assert_eq!(parse("\"Put\"\n(the)—vet"), sounds);
+    }
+
+    #[test]
+    fn it_should_parse_digits_as_word_characters() {
+        let sounds = vec![
+            Sound::new(SoundKind::Undefined, String::from("4")),
+            Sound::new(SoundKind::Th, String::from("th")),
+        ];
+
+        assert_eq!(parse("4th"), sounds);
+    }
+
+    #[test]
+    fn it_should_parse_decomposed_letters_as_one_sound() {
+        let sounds = vec![
+            Sound::new(SoundKind::Ptk, String::from("c")),
+            Sound::new(SoundKind::Undefined, String::from("a")),
+            Sound::new(SoundKind::Undefined, String::from("f")),
+            Sound::new(SoundKind::Undefined, String::from("e\u{301}")),
+        ];
+
+        assert_eq!(parse("cafe\u{301}"), sounds);
+    }
+
+    #[test]
+    fn it_should_parse_emoji_as_one_sound() {
+        let sounds = vec![
+            Sound::new(SoundKind::Ptk, String::from("p")),
+            Sound::new(SoundKind::Undefined, String::from("u")),
+            Sound::new(SoundKind::Ptk, String::from("t")),
+            Sound::new(SoundKind::Undefined, String::from(" ")),
+            Sound::new(SoundKind::Undefined, String::from("👍🏽")),
+            Sound::new(SoundKind::Undefined, String::from("🇬🇧")),
+            Sound::new(SoundKind::Undefined, String::from("👨\u{200d}👩\u{200d}👧")),
+        ];
+
+        assert_eq!(parse("put 👍🏽🇬🇧👨\u{200d}👩\u{200d}👧"), sounds);
+    }
+}
+
+#[cfg(test)]
+mod parse_with {
+    use super::{parse_with, Options, Sound, SoundKind};
+
+    #[test]
+    fn it_should_parse_with_nfc() {
+        let sounds = vec![
+            Sound::new(SoundKind::Undefined, String::from("\u{e9}")),
+            Sound::new(SoundKind::Undefined, String::from("t")),
+            Sound::new(SoundKind::Undefined, String::from("\u{e9}")),
+        ];
+
+        assert_eq!(
+            parse_with(
+                "e\u{301}te\u{301}",
+                &Options {
+                    nfc: true,
+                    ..Options::default()
+                }
+            ),
+            sounds
+        );
+    }
+
+    #[test]
+    fn it_should_parse_without_nfc() {
+        let sounds = vec![
+            Sound::new(SoundKind::Undefined, String::from("e\u{301}")),
+            Sound::new(SoundKind::Undefined, String::from("t")),
+            Sound::new(SoundKind::Undefined, String::from("e\u{301}")),
+        ];
+
+        assert_eq!(parse_with("e\u{301}te\u{301}", &Options::default()), sounds);
+    }
+}
+
+#[cfg(test)]
+mod parse_words {
+    use super::{parse_words, parse_words_with, Options, SoundKind};
+    use crate::serializer::serialize;
+    use crate::tokenizer::TokenKind;
+
+    #[test]
+    fn it_should_parse_words_empty() {
+        assert!(parse_words("").is_empty());
+    }
+
+    #[test]
+    fn it_should_parse_words_with_separators() {
+        let words = parse_words("The cat, \"Vet\"");
+
+        let texts = words.iter().map(|word| word.text()).collect::<Vec<&str>>();
+
+        assert_eq!(texts, vec!["The", " ", "cat", ",", " ", "\"", "Vet", "\""]);
+        assert_eq!(words[0].kind(), TokenKind::Word);
+        assert_eq!(words[3].kind(), TokenKind::Separator);
+        assert_eq!(words[6].span(), 10..13);
+    }
+
+    #[test]
+    fn it_should_parse_words_sounds() {
+        let words = parse_words("Cat");
+
+        let kinds = words[0]
+            .sounds()
+            .iter()
+            .map(|sound| sound.kind())
+            .collect::<Vec<&SoundKind>>();
+
+        assert_eq!(
+            kinds,
+            vec![&SoundKind::Ptk, &SoundKind::Undefined, &SoundKind::Ptk]
+        );
+    }
+
+    #[test]
+    fn it_should_parse_words_normalized() {
+        let words = parse_words("CAFE\u{301}");
+
+        assert_eq!(words[0].text(), "CAFE\u{301}");
+        assert_eq!(words[0].normalized(), "caf\u{e9}");
+    }
+
+    #[test]
+    fn it_should_round_trip_words() {
+        let text = "«Put» it\n\tthere — cafe\u{301} 👍🏽";
+
+        assert_eq!(serialize(parse_words(text)), text);
+    }
+
+    #[test]
+    fn it_should_round_trip_words_with_nfc() {
+        let text = "cafe\u{301}";
+
+        let words = parse_words_with(
+            text,
+            &Options {
+                nfc: true,
+                ..Options::default()
+            },
+        );
+
+        assert_eq!(words[0].sounds()[3].text(), "\u{e9}");
+        assert_eq!(serialize(words), text);
+    }
+}
+
+#[cfg(test)]
+mod parse_words_with {
+    use super::{parse_words_with, Options, SoundKind};
+    use crate::serializer::serialize;
+
+    fn expand() -> Options {
+        Options {
+            expand: true,
+            ..Options::default()
+        }
+    }
+
+    #[test]
+    fn it_should_parse_expanded_words() {
+        let words = parse_words_with("the 3rd of $5", &expand());
+
+        let texts = words.iter().map(|word| word.text()).collect::<Vec<&str>>();
+
+        assert_eq!(texts, vec!["the", " ", "3rd", " ", "of", " ", "$5"]);
+
+        assert!(words[2].is_expanded());
+        assert_eq!(words[2].normalized(), "third");
+        assert_eq!(words[2].span(), 4..7);
+        assert_eq!(words[2].sounds()[0].kind(), &SoundKind::Th);
+
+        assert_eq!(words[6].normalized(), "five dollars");
+        assert_eq!(words[6].span(), 11..13);
+    }
+
+    #[test]
+    fn it_should_parse_expanded_time_sounds() {
+        let words = parse_words_with("10:30", &expand());
+
+        let kinds = words[0]
+            .sounds()
+            .iter()
+            .map(|sound| sound.kind())
+            .filter(|kind| kind != &&SoundKind::Undefined)
+            .collect::<Vec<&SoundKind>>();
+
+        assert_eq!(words.len(), 1);
+        assert_eq!(
+            kinds,
+            vec![&SoundKind::Ptk, &SoundKind::Th, &SoundKind::Flap]
+        );
+    }
+
+    #[test]
+    fn it_should_round_trip_expanded_words() {
+        let text = "Dr. Smith & Co paid $5.99 at 10:30 on the 21st.";
+
+        assert_eq!(serialize(parse_words_with(text, &expand())), text);
+    }
+}
+
+#[cfg(test)]
+mod parse_initialisms {
+    use super::{parse, Sound, SoundKind};
+
+    #[test]
+    fn it_should_parse_initialism_by_letter_names() {
+        let sounds = vec![
+            Sound::new(SoundKind::Ch, String::from("H")),
+            Sound::new(SoundKind::Ptk, String::from("T")),
+            Sound::new(SoundKind::Undefined, String::from("M")),
+            Sound::new(SoundKind::Undefined, String::from("L")),
+        ];
+
+        assert_eq!(parse("HTML"), sounds);
+    }
+
+    #[test]
+    fn it_should_parse_plural_initialism() {
+        let sounds = vec![
+            Sound::new(SoundKind::Undefined, String::from("F")),
+            Sound::new(SoundKind::Undefined, String::from("A")),
+            Sound::new(SoundKind::Ptk, String::from("Q")),
+            Sound::new(SoundKind::Undefined, String::from("s")),
+        ];
+
+        assert_eq!(parse("FAQs"), sounds);
+    }
+
+    #[test]
+    fn it_should_parse_known_acronym_as_word() {
+        let sounds = vec![
+            Sound::new(SoundKind::Undefined, String::from("N")),
+            Sound::new(SoundKind::Undefined, String::from("A")),
+            Sound::new(SoundKind::Undefined, String::from("T")),
+            Sound::new(SoundKind::Undefined, String::from("O")),
+        ];
+
+        assert_eq!(parse("NATO"), sounds);
+    }
+
+    #[test]
+    fn it_should_parse_capitals_text_as_words() {
+        let sounds = parse("THE CAT SAT");
+
+        assert_eq!(sounds[0], Sound::new(SoundKind::Th, String::from("TH")));
+    }
+}
+
+#[cfg(test)]
+mod parse_with_lexicon {
+    use super::{parse_with, Options, Sound, SoundKind};
+    use crate::lexicon::Lexicon;
+
+    #[test]
+    fn it_should_parse_lexicon_entries_first() {
+        let lexicon: Lexicon = "
+            thames = th/Ptk a m e s
+            FBI = f b i
+        "
+        .parse()
+        .unwrap();
+
+        let options = Options {
+            lexicon,
+            ..Options::default()
+        };
+
+        let sounds = vec![
+            Sound::new(SoundKind::Ptk, String::from("Th")),
+            Sound::new(SoundKind::Undefined, String::from("a")),
+            Sound::new(SoundKind::Undefined, String::from("m")),
+            Sound::new(SoundKind::Undefined, String::from("e")),
+            Sound::new(SoundKind::Undefined, String::from("s")),
+            Sound::new(SoundKind::Undefined, String::from(" ")),
+            Sound::new(SoundKind::Undefined, String::from("F")),
+            Sound::new(SoundKind::Undefined, String::from("B")),
+            Sound::new(SoundKind::Undefined, String::from("I")),
+            Sound::new(SoundKind::Undefined, String::from(" ")),
+            Sound::new(SoundKind::Th, String::from("th")),
+            Sound::new(SoundKind::Undefined, String::from("e")),
+        ];
+
+        assert_eq!(parse_with("Thames FBI the", &options), sounds);
+    }
+
+    #[test]
+    fn it_should_parse_lexicon_entries_with_decomposed_letters() {
+        let mut lexicon = Lexicon::new();
+
+        lexicon
+            .insert(
+                "café",
+                &[("c", SoundKind::Ptk), ("afé", SoundKind::Undefined)],
+            )
+            .unwrap();
+
+        let options = Options {
+            lexicon,
+            ..Options::default()
+        };
+
+        let sounds = vec![
+            Sound::new(SoundKind::Ptk, String::from("C")),
+            Sound::new(SoundKind::Undefined, String::from("afe\u{301}")),
+        ];
+
+        assert_eq!(parse_with("Cafe\u{301}", &options), sounds);
+    }
+}
+
+#[cfg(test)]
+mod parse_with_rules {
+    use super::{parse_with, Options, Sound, SoundKind};
+    use crate::rules::RuleSet;
+
+    #[test]
+    fn it_should_parse_by_custom_rules() {
+        let rules: RuleSet = "
+            # aspirated only before a vowel
+            [ptk] -> Ptk / # _ V
+            sh -> Ch
+        "
+        .parse()
+        .unwrap();
+
+        let options = Options {
+            rules: Some(rules),
+            ..Options::default()
+        };
+
+        let sounds = vec![
+            Sound::new(SoundKind::Ptk, String::from("k")),
+            Sound::new(SoundKind::Undefined, String::from("i")),
+            Sound::new(SoundKind::Undefined, String::from("t")),
+            Sound::new(SoundKind::Undefined, String::from(" ")),
+            Sound::new(SoundKind::Undefined, String::from("s")),
+            Sound::new(SoundKind::Undefined, String::from("p")),
+            Sound::new(SoundKind::Undefined, String::from("a")),
+            Sound::new(SoundKind::Ch, String::from("sh")),
+        ];
+
+        assert_eq!(parse_with("kit spash", &options), sounds);
+    }
+
+    #[test]
+    fn it_should_parse_by_bundled_rules_without_custom_ones() {
+        let options = Options {
+            rules: Some(RuleSet::english().clone()),
+            ..Options::default()
+        };
+
+        assert_eq!(
+            parse_with("The text just in case", &options),
+            parse_with("The text just in case", &Options::default())
+        );
+    }
+}
+
+#[cfg(test)]
+mod parse_with_dialect {
+    use super::{parse_with, Options, Sound, SoundKind};
+    use crate::dialect::Dialect;
+
+    fn first_kinds(text: &str, dialect: Dialect) -> Vec<SoundKind> {
+        let options = Options {
+            dialect,
+            ..Options::default()
+        };
+
+        text.split(' ')
+            .map(|word| *parse_with(word, &options)[0].kind())
+            .collect()
+    }
+
+    #[test]
+    fn it_should_parse_general_american_by_default() {
+        assert_eq!(
+            first_kinds("tune duke Tuesday duty", Dialect::GeneralAmerican),
+            vec![
+                SoundKind::Ptk,
+                SoundKind::Undefined,
+                SoundKind::Ptk,
+                SoundKind::Undefined
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_parse_yod_coalescence_in_received_pronunciation() {
+        assert_eq!(
+            first_kinds("tune duke Tuesday duty", Dialect::ReceivedPronunciation),
+            vec![SoundKind::Ch, SoundKind::Dj, SoundKind::Ch, SoundKind::Dj]
+        );
+    }
+
+    #[test]
+    fn it_should_parse_same_words_in_both_dialects() {
+        assert_eq!(
+            first_kinds("tub dust the", Dialect::ReceivedPronunciation),
+            first_kinds("tub dust the", Dialect::GeneralAmerican)
+        );
+    }
+
+    #[test]
+    fn it_should_keep_source_text_in_received_pronunciation() {
+        let options = Options {
+            dialect: Dialect::ReceivedPronunciation,
+            ..Options::default()
+        };
+
+        let sounds = vec![
+            Sound::new(SoundKind::Dj, String::from("D")),
+            Sound::new(SoundKind::Undefined, String::from("uri")),
+            Sound::new(SoundKind::Ng, String::from("ng")),
+        ];
+
+        assert_eq!(parse_with("During", &options), sounds);
+    }
+}
+
+#[cfg(test)]
+mod parse_flaps_and_glottal_stops {
+    use super::{parse_with, Options, SoundKind};
+    use crate::dialect::Dialect;
+
+    fn t_kinds(text: &str, dialect: Dialect) -> Vec<SoundKind> {
+        let options = Options {
+            dialect,
+            ..Options::default()
+        };
+
+        parse_with(text, &options)
+            .iter()
+            .filter(|sound| sound.text().to_lowercase().starts_with('t'))
+            .map(|sound| *sound.kind())
+            .collect()
+    }
+
+    #[test]
+    fn it_should_parse_flaps_in_general_american() {
+        assert_eq!(
+            t_kinds("butter city water getting", Dialect::GeneralAmerican),
+            vec![SoundKind::Flap; 4]
+        );
+        assert_eq!(
+            t_kinds("waters prettier battled lottery", Dialect::GeneralAmerican),
+            vec![SoundKind::Flap; 4]
+        );
+    }
+
+    #[test]
+    fn it_should_not_parse_flaps_before_stressed_syllable() {
+        assert_eq!(
+            t_kinds("attack hotel", Dialect::GeneralAmerican),
+            vec![
+                SoundKind::Undefined,
+                SoundKind::Undefined,
+                SoundKind::Undefined
+            ]
+        );
+        assert_eq!(
+            t_kinds("hate hates protest", Dialect::GeneralAmerican),
+            vec![
+                SoundKind::Undefined,
+                SoundKind::Undefined,
+                SoundKind::Undefined,
+                SoundKind::Ptk
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_parse_flaps_across_words() {
+        assert_eq!(
+            t_kinds("a lot of", Dialect::GeneralAmerican),
+            vec![SoundKind::Flap]
+        );
+        assert_eq!(
+            t_kinds("a lot, of", Dialect::GeneralAmerican),
+            vec![SoundKind::Ptk]
+        );
+        assert_eq!(
+            t_kinds("Put a cat", Dialect::GeneralAmerican)[0],
+            SoundKind::Ptk
+        );
+    }
+
+    #[test]
+    fn it_should_parse_glottal_stops_in_received_pronunciation() {
+        assert_eq!(
+            t_kinds("button Britain kitten", Dialect::ReceivedPronunciation),
+            vec![SoundKind::GlottalStop; 3]
+        );
+        assert_eq!(
+            t_kinds("mountain", Dialect::ReceivedPronunciation),
+            vec![SoundKind::Undefined]
+        );
+    }
+
+    #[test]
+    fn it_should_parse_accents_differently() {
+        assert_eq!(
+            t_kinds("butter a lot of", Dialect::ReceivedPronunciation),
+            vec![SoundKind::Undefined, SoundKind::Undefined, SoundKind::Ptk]
+        );
+        assert_eq!(
+            t_kinds("button", Dialect::GeneralAmerican),
+            vec![SoundKind::Undefined, SoundKind::Undefined]
+        );
+    }
+}
+
+#[cfg(test)]
+mod parse_in {
+    use super::{parse_in, parse_words_in, Options, SoundKind};
+    use crate::language::{English, German};
+
+    fn kinds(text: &str) -> Vec<(String, SoundKind)> {
+        parse_in(text, &German, &Options::default())
+            .into_iter()
+            .filter(|sound| sound.kind() != &SoundKind::Undefined)
+            .map(|sound| (sound.text().to_string(), *sound.kind()))
+            .collect()
+    }
+
+    #[test]
+    fn it_should_parse_ich_and_ach() {
+        assert_eq!(
+            kinds("ich Milch Bach Buch auch Schule"),
+            vec![
+                (String::from("ch"), SoundKind::Ich),
+                (String::from("ch"), SoundKind::Ich),
+                (String::from("ch"), SoundKind::Ach),
+                (String::from("ch"), SoundKind::Ach),
+                (String::from("ch"), SoundKind::Ach),
+            ]
+        );
+        assert_eq!(kinds("wenig")[1], (String::from("g"), SoundKind::Ich));
+    }
+
+    #[test]
+    fn it_should_parse_w_as_v() {
+        assert_eq!(
+            kinds("Wasser Löwe"),
+            vec![
+                (String::from("W"), SoundKind::V),
+                (String::from("w"), SoundKind::V),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_parse_final_devoicing() {
+        assert_eq!(
+            kinds("Hund gibt Ding"),
+            vec![
+                (String::from("d"), SoundKind::Devoiced),
+                (String::from("b"), SoundKind::Devoiced),
+                (String::from("ng"), SoundKind::Ng),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_keep_gender_colon_inside_words() {
+        let words = parse_words_in("Lehrer:innen", &German, &Options::default());
+
+        assert_eq!(words.len(), 1);
+        assert_eq!(
+            parse_words_in("Lehrer:innen", &English, &Options::default()).len(),
+            3
+        );
+    }
+
+    #[test]
+    fn it_should_parse_english_as_default() {
+        assert_eq!(
+            parse_in("The text", &English, &Options::default()),
+            super::parse("The text")
+        );
+    }
+}
+
+#[cfg(test)]
+mod parse_with_profile {
+    use super::{parse_with, Options, Sound, SoundKind};
+    use crate::profile::Profile;
+
+    fn profile(code: &str) -> Options {
+        Options {
+            profile: Some(Profile::bundled(code).unwrap()),
+            ..Options::default()
+        }
+    }
+
+    #[test]
+    fn it_should_parse_rules_of_profile_first() {
+        assert_eq!(
+            parse_with("all", &profile("ja")),
+            vec![
+                Sound::new(SoundKind::Undefined, String::from("a")),
+                Sound::new(SoundKind::L, String::from("ll")),
+            ]
+        );
+        assert_eq!(
+            parse_with("vote", &profile("es"))[0].rule(),
+            Some("v, merged with b by Spanish speakers")
+        );
+    }
+
+    #[test]
+    fn it_should_mark_final_consonants() {
+        let finals = |text: &str| {
+            parse_with(text, &Options::default())
+                .iter()
+                .filter(|sound| sound.is_final())
+                .map(|sound| sound.text().to_string())
+                .collect::<Vec<String>>()
+        };
+
+        assert_eq!(finals("Big dogs"), vec!["g", "g", "s"]);
+        assert!(finals("made so").is_empty());
+    }
+
+    #[test]
+    fn it_should_fall_back_to_rules_of_language() {
+        assert_eq!(*parse_with("the", &profile("ja"))[0].kind(), SoundKind::Th);
+        assert_eq!(
+            parse_with("put", &profile("ru")),
+            parse_with("put", &Options::default())
+        );
+    }
 }
@@ -0,0 +1,282 @@
+use crate::error::Error;
+use crate::rules::{Rule, RuleSet};
+use crate::sound::SoundKind;
+use std::str::FromStr;
+
+/// Bundled profiles by the language code of the learners
+const BUNDLED_PROFILES: [(&str, &str); 4] = [
+    ("ru", include_str!("../data/profiles/ru.profile")),
+    ("es", include_str!("../data/profiles/es.profile")),
+    ("ja", include_str!("../data/profiles/ja.profile")),
+    ("zh", include_str!("../data/profiles/zh.profile")),
+];
+
+/// Comment line prefix of the profile source
+const COMMENT_PREFIX: char = '#';
+
+/// Prefix of the name line of the profile source
+const NAME_PREFIX: &str = "name =";
+
+/// Prefix of the line of the final consonants weight of the profile source
+const FINAL_PREFIX: &str = "final =";
+
+/// Separator between the pattern and its sound kind, rule lines contain it
+const RULE_MARKER: &str = "->";
+
+/// Difficulty profile of the learners with the same first language:
+/// sounds to highlight with their weights and extra rules checked before the rules of the language
+///
+/// The text format has `name = ...` line, lines of the sound kind with its weight (`Th 1.5`),
+/// optional `final = ...` line with the weight of the final consonants and rule lines (`r -> R`).
+/// Lines started with `#` are comments.
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::{highlight_with, Options, Profile, SoundKind};
+///
+/// let profile = Profile::bundled("ja").unwrap();
+///
+/// assert_eq!(profile.name(), "Japanese");
+/// assert_eq!(profile.weight(SoundKind::R), 1.5);
+///
+/// let options = Options {
+///     profile: Some(profile),
+///     ..Options::default()
+/// };
+///
+/// assert_eq!(
+///     highlight_with("Put a rule", &options),
+///     "Put a <span class='R'>r</span>u<span class='L'>l</span>e"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Profile {
+    name: String,
+    weights: Vec<(SoundKind, f64)>,
+    final_weight: f64,
+    rules: RuleSet,
+}
+
+impl Profile {
+    /// Returns the bundled profile by the language code of the learners: "ru", "es", "ja" or "zh"
+    pub fn bundled(code: &str) -> Result<Self, Error> {
+        let (_, source) = BUNDLED_PROFILES
+            .iter()
+            .find(|(bundled, _)| *bundled == code)
+            .ok_or_else(|| Error::UnknownProfile(code.to_string()))?;
+
+        source.parse()
+    }
+
+    /// Returns language codes of the bundled profiles
+    pub fn codes() -> Vec<&'static str> {
+        BUNDLED_PROFILES.iter().map(|(code, _)| *code).collect()
+    }
+
+    /// Returns name of the profile, e.g. "Spanish"
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns sound kinds of the profile with their weights
+    pub fn weights(&self) -> &[(SoundKind, f64)] {
+        &self.weights
+    }
+
+    /// Returns weight of the sound kind, zero if the profile has no such sound
+    pub fn weight(&self, kind: SoundKind) -> f64 {
+        self.weights
+            .iter()
+            .find(|(weighted, _)| *weighted == kind)
+            .map_or(0.0, |(_, weight)| *weight)
+    }
+
+    /// Returns true if sounds of the kind are highlighted for the learners
+    pub fn is_highlighted(&self, kind: SoundKind) -> bool {
+        self.weight(kind) > 0.0
+    }
+
+    /// Returns weight of the final consonants, zero if the profile does not highlight them
+    pub fn final_weight(&self) -> f64 {
+        self.final_weight
+    }
+
+    /// Returns extra rules of the profile
+    pub fn rules(&self) -> &RuleSet {
+        &self.rules
+    }
+}
+
+impl FromStr for Profile {
+    type Err = Error;
+
+    /// Parses the profile from the text format
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let mut profile = Profile::default();
+
+        for (index, line) in source.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with(COMMENT_PREFIX) {
+                continue;
+            }
+
+            let syntax_error = |message: String| Error::Syntax {
+                line: index + 1,
+                message,
+            };
+
+            if let Some(name) = line.strip_prefix(NAME_PREFIX) {
+                profile.name = name.trim().to_string();
+
+                continue;
+            }
+
+            if let Some(weight) = line.strip_prefix(FINAL_PREFIX) {
+                profile.final_weight = parse_weight(weight).map_err(syntax_error)?;
+
+                continue;
+            }
+
+            if line.contains(RULE_MARKER) {
+                let rule = line.parse::<Rule>().map_err(|error| match error {
+                    Error::Syntax { message, .. } => syntax_error(message),
+                    error => error,
+                })?;
+
+                profile.rules.push(rule);
+
+                continue;
+            }
+
+            let (kind, weight) = line.split_once(char::is_whitespace).ok_or_else(|| {
+                syntax_error(String::from("expected the sound kind and its weight"))
+            })?;
+
+            let kind = kind
+                .parse::<SoundKind>()
+                .map_err(|error| syntax_error(error.to_string()))?;
+            let weight = parse_weight(weight).map_err(syntax_error)?;
+
+            profile.weights.push((kind, weight));
+        }
+
+        Ok(profile)
+    }
+}
+
+/// Parses the weight of the sounds, e.g. "1.5"
+fn parse_weight(weight: &str) -> Result<f64, String> {
+    weight
+        .trim()
+        .parse::<f64>()
+        .map_err(|_| format!("invalid weight '{}'", weight.trim()))
+}
+
+#[cfg(test)]
+mod bundled {
+    use super::{Error, Profile, SoundKind};
+
+    #[test]
+    fn it_should_parse_bundled_profiles() {
+        for code in Profile::codes() {
+            let profile = Profile::bundled(code).unwrap();
+
+            assert!(!profile.name().is_empty());
+            assert!(!profile.weights().is_empty());
+        }
+    }
+
+    #[test]
+    fn it_should_keep_original_sounds_for_russian_speakers() {
+        let profile = Profile::bundled("ru").unwrap();
+
+        let kinds = profile
+            .weights()
+            .iter()
+            .map(|(kind, _)| *kind)
+            .collect::<Vec<SoundKind>>();
+
+        assert_eq!(
+            kinds,
+            vec![
+                SoundKind::Ptk,
+                SoundKind::Th,
+                SoundKind::W,
+                SoundKind::V,
+                SoundKind::Ng,
+                SoundKind::Ch,
+                SoundKind::Dj
+            ]
+        );
+        assert!(profile.rules().is_empty());
+    }
+
+    #[test]
+    fn it_should_weight_final_consonants_for_chinese_speakers() {
+        let profile = Profile::bundled("zh").unwrap();
+
+        assert_eq!(profile.final_weight(), 1.5);
+        assert_eq!(profile.weight(SoundKind::R), 0.0);
+        assert_eq!(Profile::bundled("ja").unwrap().final_weight(), 0.0);
+    }
+
+    #[test]
+    fn it_should_report_unknown_profile() {
+        assert_eq!(
+            Profile::bundled("xx"),
+            Err(Error::UnknownProfile(String::from("xx")))
+        );
+    }
+}
+
+#[cfg(test)]
+mod from_str {
+    use super::{Error, Profile, SoundKind};
+
+    #[test]
+    fn it_should_parse_profile() {
+        let profile: Profile = "
+            # comment
+            name = Custom
+
+            Th 2
+            final = 0.5
+            r -> R
+        "
+        .parse()
+        .unwrap();
+
+        assert_eq!(profile.name(), "Custom");
+        assert_eq!(profile.weight(SoundKind::Th), 2.0);
+        assert!(!profile.is_highlighted(SoundKind::Ptk));
+        assert_eq!(profile.final_weight(), 0.5);
+        assert_eq!(profile.rules().len(), 1);
+    }
+
+    #[test]
+    fn it_should_report_line() {
+        assert_eq!(
+            "name = Custom\nTh heavy".parse::<Profile>(),
+            Err(Error::Syntax {
+                line: 2,
+                message: String::from("invalid weight 'heavy'")
+            })
+        );
+        assert_eq!(
+            "final = most".parse::<Profile>(),
+            Err(Error::Syntax {
+                line: 1,
+                message: String::from("invalid weight 'most'")
+            })
+        );
+        assert_eq!(
+            "\n\nr -> Rr".parse::<Profile>(),
+            Err(Error::Syntax {
+                line: 3,
+                message: String::from("unknown sound kind 'Rr'")
+            })
+        );
+    }
+}
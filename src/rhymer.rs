@@ -0,0 +1,295 @@
+use crate::options::Options;
+use crate::parser::{parse_with, parse_words_with};
+use crate::rules::vowel_groups;
+use crate::sound::Sound;
+use crate::word::Word;
+use std::ops::Range;
+
+/// Weak final vowels, the rhyme starts from the vowel before them, e.g. "ake" in "make"
+const WEAK_FINAL_VOWELS: [&str; 2] = ["e", "y"];
+
+/// Function words that neither break nor join the alliteration, e.g. "a" in "Peter picked a peck"
+const TRANSPARENT_WORDS: [&str; 11] = [
+    "a", "an", "the", "of", "and", "to", "in", "on", "at", "for", "with",
+];
+
+/// Group of the words with the same key: the rhyme or the initial sound
+#[derive(Debug, Clone, PartialEq)]
+pub struct WordGroup {
+    key: String,
+    words: Vec<(String, Range<usize>)>,
+}
+
+impl WordGroup {
+    /// Returns the common key of the words, e.g. "at" for "cat" and "hat"
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns source text of the words with their byte spans in the source text
+    pub fn words(&self) -> &[(String, Range<usize>)] {
+        &self.words
+    }
+}
+
+/// Returns the rhyme key of the word: its sounds from the last stressed vowel onward
+///
+/// Stress is approximated: the last vowel is taken unless it is a weak final "e" or "y".
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::rhyme_key;
+///
+/// assert_eq!(rhyme_key("cat"), Some(String::from("at")));
+/// assert_eq!(rhyme_key("Make"), Some(String::from("ake")));
+/// assert_eq!(rhyme_key("happy"), Some(String::from("appy")));
+/// assert_eq!(rhyme_key("psst"), None);
+/// ```
+pub fn rhyme_key<T: AsRef<str>>(word: T) -> Option<String> {
+    rhyme_key_of(&parse_with(word, &Options::default()))
+}
+
+/// Find groups of the rhyming words in the text
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::rhymes;
+///
+/// let rhymes = rhymes("The cat in the hat sat on the mat");
+///
+/// assert_eq!(rhymes.len(), 1);
+/// assert_eq!(rhymes[0].key(), "at");
+/// assert_eq!(rhymes[0].words().len(), 4);
+/// ```
+pub fn rhymes<T: AsRef<str>>(text: T) -> Vec<WordGroup> {
+    rhymes_with(text, &Options::default())
+}
+
+/// Find groups of the rhyming words in the text with the options
+pub fn rhymes_with<T: AsRef<str>>(text: T, options: &Options) -> Vec<WordGroup> {
+    group_rhymes(&parse_words_with(text, options))
+}
+
+/// Find runs of the consecutive words with the same initial sound
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::alliterations;
+///
+/// let alliterations = alliterations("Peter Piper picked a peck of pickled peppers");
+///
+/// assert_eq!(alliterations.len(), 1);
+/// assert_eq!(alliterations[0].key(), "p");
+/// assert_eq!(alliterations[0].words().len(), 6);
+/// ```
+pub fn alliterations<T: AsRef<str>>(text: T) -> Vec<WordGroup> {
+    alliterations_with(text, &Options::default())
+}
+
+/// Find runs of the consecutive words with the same initial sound with the options
+pub fn alliterations_with<T: AsRef<str>>(text: T, options: &Options) -> Vec<WordGroup> {
+    let mut alliterations = vec![];
+    let mut run: Option<WordGroup> = None;
+
+    let words = parse_words_with(text, options);
+
+    for word in words.iter().filter(|word| !word.is_separator()) {
+        if TRANSPARENT_WORDS.contains(&word.normalized()) {
+            continue;
+        }
+
+        let initial = match word.sounds().first() {
+            Some(sound) => sound.text().to_lowercase(),
+            None => continue,
+        };
+
+        match &mut run {
+            Some(group) if group.key == initial => {
+                group.words.push((word.text().to_string(), word.span()))
+            }
+            _ => {
+                alliterations.extend(run.take().filter(|group| group.words.len() > 1));
+
+                run = Some(WordGroup {
+                    key: initial,
+                    words: vec![(word.text().to_string(), word.span())],
+                });
+            }
+        }
+    }
+
+    alliterations.extend(run.filter(|group| group.words.len() > 1));
+
+    alliterations
+}
+
+/// Groups the words by their rhyme keys, a group has at least two different words
+pub(crate) fn group_rhymes(words: &[Word]) -> Vec<WordGroup> {
+    let mut groups: Vec<WordGroup> = vec![];
+
+    for word in words.iter().filter(|word| !word.is_separator()) {
+        let key = match rhyme_key_of(word.sounds()) {
+            Some(key) => key,
+            None => continue,
+        };
+
+        let entry = (word.text().to_string(), word.span());
+
+        match groups.iter_mut().find(|group| group.key == key) {
+            Some(group) => group.words.push(entry),
+            None => groups.push(WordGroup {
+                key,
+                words: vec![entry],
+            }),
+        }
+    }
+
+    groups.retain(|group| {
+        group
+            .words
+            .iter()
+            .any(|(text, _)| text.to_lowercase() != group.words[0].0.to_lowercase())
+    });
+
+    groups
+}
+
+fn rhyme_key_of(sounds: &[Sound]) -> Option<String> {
+    let texts = sounds
+        .iter()
+        .map(|sound| sound.text().to_lowercase())
+        .collect::<Vec<String>>();
+
+    let mut groups = vowel_groups(&texts);
+
+    let last = groups.pop()?;
+
+    let start = match groups.last() {
+        Some(previous)
+            if last.end == texts.len()
+                && last.len() == 1
+                && WEAK_FINAL_VOWELS.contains(&texts[last.start].as_str()) =>
+        {
+            previous.start
+        }
+        _ => last.start,
+    };
+
+    Some(texts[start..].concat())
+}
+
+#[cfg(test)]
+mod rhyme_key {
+    use super::rhyme_key;
+
+    #[test]
+    fn it_should_find_rhyme_key() {
+        assert_eq!(rhyme_key("sing"), Some(String::from("ing")));
+        assert_eq!(rhyme_key("boat"), Some(String::from("oat")));
+        assert_eq!(rhyme_key("see"), Some(String::from("ee")));
+        assert_eq!(rhyme_key("me"), Some(String::from("e")));
+    }
+
+    #[test]
+    fn it_should_skip_weak_final_vowels() {
+        assert_eq!(rhyme_key("cake"), Some(String::from("ake")));
+        assert_eq!(rhyme_key("funny"), Some(String::from("unny")));
+        assert_eq!(rhyme_key("yes"), Some(String::from("es")));
+    }
+
+    #[test]
+    fn it_should_not_find_rhyme_key_without_vowels() {
+        assert_eq!(rhyme_key(""), None);
+        assert_eq!(rhyme_key("hmm"), None);
+    }
+}
+
+#[cfg(test)]
+mod rhymes {
+    use super::{rhymes, rhymes_with, Options};
+
+    fn keys(text: &str) -> Vec<(String, Vec<String>)> {
+        rhymes(text)
+            .into_iter()
+            .map(|group| {
+                (
+                    group.key().to_string(),
+                    group.words().iter().map(|(word, _)| word.clone()).collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn it_should_group_rhymes() {
+        assert_eq!(
+            keys("Make a cake, sing a song, take a ring"),
+            vec![
+                (
+                    String::from("ake"),
+                    vec![
+                        String::from("Make"),
+                        String::from("cake"),
+                        String::from("take")
+                    ]
+                ),
+                (
+                    String::from("ing"),
+                    vec![String::from("sing"), String::from("ring")]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_not_group_repeated_word() {
+        assert!(keys("Cat, cat!").is_empty());
+        assert!(keys("").is_empty());
+    }
+
+    #[test]
+    fn it_should_group_rhymes_with_spans() {
+        let options = Options {
+            expand: true,
+            ..Options::default()
+        };
+
+        let rhymes = rhymes_with("Hen 10", &options);
+
+        assert_eq!(rhymes[0].key(), "en");
+        assert_eq!(rhymes[0].words()[1], (String::from("10"), 4..6));
+    }
+}
+
+#[cfg(test)]
+mod alliterations {
+    use super::alliterations;
+
+    fn keys(text: &str) -> Vec<(String, usize)> {
+        alliterations(text)
+            .into_iter()
+            .map(|group| (group.key().to_string(), group.words().len()))
+            .collect()
+    }
+
+    #[test]
+    fn it_should_find_runs() {
+        assert_eq!(
+            keys("Big bad bears, then three thin thieves and sixty snakes"),
+            vec![
+                (String::from("b"), 3),
+                (String::from("th"), 4),
+                (String::from("s"), 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_break_runs_by_other_sounds() {
+        assert!(keys("Peter likes pickles").is_empty());
+        assert!(keys("the a of").is_empty());
+    }
+}
@@ -0,0 +1,835 @@
+use crate::error::Error;
+use crate::scanner::Scanner;
+use crate::sound::{Sound, SoundKind};
+use std::collections::HashMap;
+use std::fs;
+use std::ops::Range;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::OnceLock;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Bundled English rules
+const ENGLISH_RULES: &str = include_str!("../data/en.rules");
+
+/// Comment line prefix of the rules source
+const COMMENT_PREFIX: char = '#';
+
+/// Separator between the pattern and its sound kind in the rules source
+const KIND_SEPARATOR: &str = "->";
+
+/// Separator between the sound kind and the context in the rules source
+const CONTEXT_SEPARATOR: char = '/';
+
+/// Quote of the rule description in the rules source
+const DESCRIPTION_QUOTE: char = '"';
+
+/// Placeholder of the pattern in the context
+const PATTERN_PLACEHOLDER: char = '_';
+
+/// Letters matched by the vowel class `V`
+const VOWELS: [char; 6] = ['a', 'e', 'i', 'o', 'u', 'y'];
+
+/// Element of the pattern or of the context
+#[derive(Debug, Clone, PartialEq)]
+enum Element {
+    /// Word start or end, `#`
+    Boundary,
+    /// Any vowel letter, `V`
+    Vowel,
+    /// Any consonant letter, `C`
+    Consonant,
+    /// Lowercase grapheme, e.g. `t`
+    Grapheme(String),
+    /// One of the lowercase graphemes, e.g. `[ptk]`, or none of them, e.g. `[^ptk]`
+    Class {
+        graphemes: Vec<String>,
+        negated: bool,
+    },
+    /// One of the element sequences, e.g. `(tt|t)`, an empty sequence makes it optional, e.g. `(|s)`
+    Alternation(Vec<Vec<Element>>),
+}
+
+/// Position of the next grapheme to match: the offset from the cursor or the index in the next word
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Position {
+    Word(isize),
+    NextWord(usize),
+}
+
+impl Element {
+    /// Returns true if the grapheme matches the element, `None` is out of the word
+    fn matches(&self, grapheme: Option<&str>) -> bool {
+        let grapheme = match grapheme {
+            Some(grapheme) => grapheme.to_lowercase(),
+            None => return *self == Element::Boundary,
+        };
+
+        match self {
+            Element::Boundary => false,
+            Element::Vowel => is_vowel(&grapheme),
+            Element::Consonant => {
+                base_letter(&grapheme).is_some_and(|c| c.is_alphabetic() && !VOWELS.contains(&c))
+            }
+            Element::Grapheme(expected) => *expected == grapheme,
+            Element::Class { graphemes, negated } => graphemes.contains(&grapheme) != *negated,
+            Element::Alternation(_) => false,
+        }
+    }
+
+    /// Returns positions after the element matched at the position, there are several for the alternation.
+    /// Backward matching steps to the left of the cursor, forward matching steps into the next word after `#`.
+    fn step(&self, position: Position, scanner: &Scanner, backward: bool) -> Vec<Position> {
+        if let Element::Alternation(alternatives) = self {
+            return alternatives
+                .iter()
+                .flat_map(|alternative| step_all(alternative, vec![position], scanner, backward))
+                .collect();
+        }
+
+        let grapheme = match position {
+            Position::Word(offset) if backward => scanner.peek_at(offset - 1),
+            Position::Word(offset) => scanner.peek_at(offset),
+            Position::NextWord(index) => scanner.peek_next_word(index),
+        };
+
+        if !self.matches(grapheme) {
+            return vec![];
+        }
+
+        let next = match (self, position) {
+            (_, Position::Word(offset)) if backward => Position::Word(offset - 1),
+            (Element::Boundary, Position::Word(_)) => Position::NextWord(0),
+            (_, Position::Word(offset)) => Position::Word(offset + 1),
+            (_, Position::NextWord(index)) => Position::NextWord(index + 1),
+        };
+
+        vec![next]
+    }
+
+    /// Returns true if the element is or contains the word boundary
+    fn has_boundary(&self) -> bool {
+        match self {
+            Element::Boundary => true,
+            Element::Alternation(alternatives) => {
+                alternatives.iter().flatten().any(Element::has_boundary)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Returns positions after the elements matched at any of the positions
+fn step_all(
+    elements: &[Element],
+    mut positions: Vec<Position>,
+    scanner: &Scanner,
+    backward: bool,
+) -> Vec<Position> {
+    let ordered: Box<dyn Iterator<Item = &Element>> = if backward {
+        Box::new(elements.iter().rev())
+    } else {
+        Box::new(elements.iter())
+    };
+
+    for element in ordered {
+        let mut next = vec![];
+
+        for position in positions {
+            for after in element.step(position, scanner, backward) {
+                if !next.contains(&after) {
+                    next.push(after);
+                }
+            }
+        }
+
+        positions = next;
+    }
+
+    positions
+}
+
+/// Returns lowercase graphemes the elements can start with, `None` if they can start with any
+fn first_graphemes(elements: &[Element]) -> Option<Vec<String>> {
+    match elements.first()? {
+        Element::Grapheme(grapheme) => Some(vec![grapheme.clone()]),
+        Element::Class {
+            graphemes,
+            negated: false,
+        } => Some(graphemes.clone()),
+        Element::Alternation(alternatives) => {
+            let mut graphemes = vec![];
+
+            for alternative in alternatives {
+                for grapheme in first_graphemes(alternative)? {
+                    if !graphemes.contains(&grapheme) {
+                        graphemes.push(grapheme);
+                    }
+                }
+            }
+
+            Some(graphemes)
+        }
+        _ => None,
+    }
+}
+
+/// Rule of the sound: the grapheme pattern in the left and right context gives the sound kind.
+/// An optional description in quotes explains the rule, it is kept in the produced sounds.
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::{Rule, SoundKind};
+///
+/// let rule: Rule = "[ptc] -> Ptk / # _ \"word-initial p, t or c → aspirated Ptk\"".parse().unwrap();
+///
+/// assert_eq!(rule.kind(), SoundKind::Ptk);
+/// assert_eq!(rule.source(), "[ptc] -> Ptk / # _");
+/// assert_eq!(rule.description(), "word-initial p, t or c → aspirated Ptk");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rule {
+    source: String,
+    description: Option<String>,
+    pattern: Vec<Element>,
+    left: Vec<Element>,
+    right: Vec<Element>,
+    kind: SoundKind,
+}
+
+impl Rule {
+    /// Returns source text of the rule
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns description of the rule or its source text if there is no description
+    pub fn description(&self) -> &str {
+        self.description.as_deref().unwrap_or(&self.source)
+    }
+
+    /// Returns sound kind of the rule
+    pub fn kind(&self) -> SoundKind {
+        self.kind
+    }
+
+    /// Returns count of graphemes matched by the pattern at the cursor of the scanner,
+    /// the longest one if the pattern has alternatives, `None` if the rule does not match
+    fn matches(&self, scanner: &Scanner) -> Option<usize> {
+        if step_all(&self.left, vec![Position::Word(0)], scanner, true).is_empty() {
+            return None;
+        }
+
+        step_all(&self.pattern, vec![Position::Word(0)], scanner, false)
+            .into_iter()
+            .filter_map(|position| match position {
+                Position::Word(offset) if offset > 0 => Some(offset),
+                _ => None,
+            })
+            .filter(|&offset| self.matches_right(scanner, offset))
+            .max()
+            .map(|offset| offset as usize)
+    }
+
+    /// Returns true if the right context matches graphemes after the pattern of the length,
+    /// elements after the word end `#` match the next word
+    fn matches_right(&self, scanner: &Scanner, length: isize) -> bool {
+        !step_all(&self.right, vec![Position::Word(length)], scanner, false).is_empty()
+    }
+
+    /// Returns lowercase graphemes the rule can start with, `None` if it can start with any
+    fn first_graphemes(&self) -> Option<Vec<String>> {
+        first_graphemes(&self.pattern)
+    }
+}
+
+impl FromStr for Rule {
+    type Err = Error;
+
+    /// Parses the rule from `pattern -> Kind / left _ right`, the context is optional
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        parse_rule(source).map_err(|message| Error::Syntax { line: 1, message })
+    }
+}
+
+/// Parses the rule, the error is a message without the line
+fn parse_rule(source: &str) -> Result<Rule, String> {
+    let source = source.trim();
+
+    let (definition, description) = match source.split_once(DESCRIPTION_QUOTE) {
+        Some((definition, description)) => match description.strip_suffix(DESCRIPTION_QUOTE) {
+            Some(description) if !description.contains(DESCRIPTION_QUOTE) => {
+                (definition.trim(), Some(description.to_string()))
+            }
+            _ => return Err(format!("expected closing '{}'", DESCRIPTION_QUOTE)),
+        },
+        None => (source, None),
+    };
+
+    let (pattern, rest) = definition
+        .split_once(KIND_SEPARATOR)
+        .ok_or_else(|| format!("expected '{}'", KIND_SEPARATOR))?;
+
+    let (kind, context) = match rest.split_once(CONTEXT_SEPARATOR) {
+        Some((kind, context)) => (kind, Some(context)),
+        None => (rest, None),
+    };
+
+    let (left, right) = match context {
+        Some(context) => context
+            .split_once(PATTERN_PLACEHOLDER)
+            .ok_or_else(|| format!("expected '{}' in the context", PATTERN_PLACEHOLDER))?,
+        None => ("", ""),
+    };
+
+    let pattern = parse_elements(pattern)?;
+
+    if pattern.is_empty() {
+        return Err(String::from("the pattern is empty"));
+    }
+
+    if pattern.iter().any(Element::has_boundary) {
+        return Err(String::from("the pattern contains the word boundary"));
+    }
+
+    Ok(Rule {
+        source: definition.to_string(),
+        description,
+        pattern,
+        left: parse_elements(left)?,
+        right: parse_elements(right)?,
+        kind: kind
+            .trim()
+            .parse::<SoundKind>()
+            .map_err(|error| error.to_string())?,
+    })
+}
+
+/// Ordered rules of the sounds with the compiled matcher. The first matching rule wins,
+/// graphemes without a matching rule are `Undefined`.
+///
+/// The text format has one rule per line (see [`Rule`]), lines started with `#` are comments.
+/// The bundled English rules are in `data/en.rules`.
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::{highlight_with, Options, RuleSet};
+///
+/// let rules: RuleSet = "
+/// ## voiced th only between vowels
+/// th -> Th / V _ V
+/// "
+/// .parse()
+/// .unwrap();
+///
+/// let options = Options {
+///     rules: Some(rules),
+///     ..Options::default()
+/// };
+///
+/// assert_eq!(highlight_with("the other", &options), "the o<span class='Th'>th</span>er");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RuleSet {
+    rules: Vec<Rule>,
+    index: HashMap<String, Vec<usize>>,
+    any: Vec<usize>,
+}
+
+impl RuleSet {
+    /// Creates new empty RuleSet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the bundled English rules common for the dialects
+    pub fn english() -> &'static RuleSet {
+        static RULES: OnceLock<RuleSet> = OnceLock::new();
+
+        RULES.get_or_init(|| ENGLISH_RULES.parse().expect("bundled rules are valid"))
+    }
+
+    /// Loads rules from the file in the text format
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        fs::read_to_string(path)
+            .map_err(|error| Error::Io(error.to_string()))?
+            .parse()
+    }
+
+    /// Appends the rule, it has the lowest priority
+    pub fn push(&mut self, rule: Rule) {
+        let position = self.rules.len();
+
+        match rule.first_graphemes() {
+            Some(graphemes) => {
+                for grapheme in graphemes {
+                    self.index.entry(grapheme).or_default().push(position);
+                }
+            }
+            None => self.any.push(position),
+        }
+
+        self.rules.push(rule);
+    }
+
+    /// Returns the rules in priority order
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    /// Returns count of the rules
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Returns true if there are no rules
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Returns the first rule matching graphemes at the cursor of the scanner with count of the matched graphemes
+    fn find(&self, scanner: &Scanner) -> Option<(&Rule, usize)> {
+        let grapheme = scanner.peek().to_lowercase();
+
+        let mut candidates = self
+            .index
+            .get(&grapheme)
+            .into_iter()
+            .flatten()
+            .chain(self.any.iter())
+            .copied()
+            .collect::<Vec<usize>>();
+
+        candidates.sort_unstable();
+
+        candidates
+            .into_iter()
+            .map(|position| &self.rules[position])
+            .find_map(|rule| rule.matches(scanner).map(|length| (rule, length)))
+    }
+
+    /// Parse the word graphemes to sounds by the rules
+    pub(crate) fn parse_word(&self, scanner: Scanner) -> Vec<Sound> {
+        parse_word_by(&[self], scanner)
+    }
+}
+
+/// Parse the word graphemes to sounds by the rule sets, a rule set is checked before the next ones
+pub(crate) fn parse_word_by(rule_sets: &[&RuleSet], mut scanner: Scanner) -> Vec<Sound> {
+    let mut sounds = vec![];
+
+    while !scanner.is_done() {
+        let rule = rule_sets.iter().find_map(|rules| rules.find(&scanner));
+
+        let sound = match rule {
+            Some((rule, length)) => {
+                let text = (0..length)
+                    .map(|_| scanner.pop().to_string())
+                    .collect::<String>();
+
+                Sound::with_rule(rule.kind, text, rule.description().to_string())
+            }
+            None => Sound::new(SoundKind::Undefined, scanner.pop().to_string()),
+        };
+
+        sounds.push(sound);
+    }
+
+    sounds
+}
+
+impl FromStr for RuleSet {
+    type Err = Error;
+
+    /// Parses the rules from the text format
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let mut rules = RuleSet::new();
+
+        for (index, line) in source.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with(COMMENT_PREFIX) {
+                continue;
+            }
+
+            let rule = parse_rule(line).map_err(|message| Error::Syntax {
+                line: index + 1,
+                message,
+            })?;
+
+            rules.push(rule);
+        }
+
+        Ok(rules)
+    }
+}
+
+/// Parses elements of the pattern or of the context, whitespaces are ignored
+fn parse_elements(source: &str) -> Result<Vec<Element>, String> {
+    let source = source.split_whitespace().collect::<String>();
+
+    let mut elements = vec![];
+    let mut graphemes = source.graphemes(true);
+
+    while let Some(grapheme) = graphemes.next() {
+        let element = match grapheme {
+            "#" => Element::Boundary,
+            "V" => Element::Vowel,
+            "C" => Element::Consonant,
+            "[" => {
+                let mut class = vec![];
+
+                loop {
+                    match graphemes.next() {
+                        Some("]") => break,
+                        Some(grapheme) => class.push(grapheme.to_lowercase()),
+                        None => return Err(String::from("expected ']'")),
+                    }
+                }
+
+                let negated = class.first().is_some_and(|first| first == "^");
+
+                if negated {
+                    class.remove(0);
+                }
+
+                if class.is_empty() {
+                    return Err(String::from("the class is empty"));
+                }
+
+                Element::Class {
+                    graphemes: class,
+                    negated,
+                }
+            }
+            "(" => {
+                let mut group = String::new();
+
+                loop {
+                    match graphemes.next() {
+                        Some(")") => break,
+                        Some("(") => return Err(String::from("nested alternation")),
+                        Some(grapheme) => group.push_str(grapheme),
+                        None => return Err(String::from("expected ')'")),
+                    }
+                }
+
+                let alternatives = group
+                    .split('|')
+                    .map(parse_elements)
+                    .collect::<Result<Vec<Vec<Element>>, String>>()?;
+
+                if alternatives.len() < 2 {
+                    return Err(String::from("expected '|' in the alternation"));
+                }
+
+                Element::Alternation(alternatives)
+            }
+            "]" | "_" | ")" | "|" => return Err(format!("unexpected '{}'", grapheme)),
+            grapheme => Element::Grapheme(grapheme.to_lowercase()),
+        };
+
+        elements.push(element);
+    }
+
+    Ok(elements)
+}
+
+/// Returns true if the base letter of the grapheme is a vowel, e.g. "é"
+pub(crate) fn is_vowel(grapheme: &str) -> bool {
+    base_letter(grapheme).is_some_and(|c| VOWELS.contains(&c))
+}
+
+/// Returns index ranges of the runs of vowel sounds in the lowercase sound texts of the word,
+/// the initial "y" before a vowel is a consonant, e.g. "yes"
+pub(crate) fn vowel_groups(texts: &[String]) -> Vec<Range<usize>> {
+    let is_vowel_at = |index: usize| {
+        let text = &texts[index];
+
+        !text.is_empty()
+            && text.graphemes(true).all(is_vowel)
+            && !(index == 0 && text == "y" && texts.len() > 1)
+    };
+
+    let mut groups: Vec<Range<usize>> = vec![];
+
+    for index in 0..texts.len() {
+        match groups.last_mut() {
+            Some(group) if is_vowel_at(index) && group.end == index => group.end += 1,
+            _ if is_vowel_at(index) => groups.push(index..index + 1),
+            _ => {}
+        }
+    }
+
+    groups
+}
+
+/// Returns the lowercase base letter of the grapheme, e.g. "e" for "É"
+fn base_letter(grapheme: &str) -> Option<char> {
+    grapheme.nfd().next().and_then(|c| c.to_lowercase().next())
+}
+
+#[cfg(test)]
+mod rule_from_str {
+    use super::{parse_rule, Element, Error, Rule, SoundKind};
+
+    #[test]
+    fn it_should_parse_rule() {
+        let rule: Rule = "n[gk] -> Ng".parse().unwrap();
+
+        assert_eq!(rule.kind(), SoundKind::Ng);
+        assert_eq!(
+            rule.pattern,
+            vec![
+                Element::Grapheme(String::from("n")),
+                Element::Class {
+                    graphemes: vec![String::from("g"), String::from("k")],
+                    negated: false
+                }
+            ]
+        );
+        assert!(rule.left.is_empty() && rule.right.is_empty());
+        assert_eq!(rule.description(), "n[gk] -> Ng");
+    }
+
+    #[test]
+    fn it_should_parse_description() {
+        let rule: Rule = "v -> V / # _ \"word-initial v → V\"".parse().unwrap();
+
+        assert_eq!(rule.source(), "v -> V / # _");
+        assert_eq!(rule.description(), "word-initial v → V");
+        assert_eq!(rule.left, vec![Element::Boundary]);
+    }
+
+    #[test]
+    fn it_should_parse_context() {
+        let rule: Rule = "t -> Ptk / # _ [^s] V".parse().unwrap();
+
+        assert_eq!(rule.left, vec![Element::Boundary]);
+        assert_eq!(
+            rule.right,
+            vec![
+                Element::Class {
+                    graphemes: vec![String::from("s")],
+                    negated: true
+                },
+                Element::Vowel
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_parse_alternation() {
+        let rule: Rule = "(tt|t) -> Flap / V _ (er|y) (|s) #".parse().unwrap();
+
+        assert_eq!(
+            rule.pattern,
+            vec![Element::Alternation(vec![
+                vec![
+                    Element::Grapheme(String::from("t")),
+                    Element::Grapheme(String::from("t"))
+                ],
+                vec![Element::Grapheme(String::from("t"))]
+            ])]
+        );
+        assert_eq!(
+            rule.right[1],
+            Element::Alternation(vec![vec![], vec![Element::Grapheme(String::from("s"))]])
+        );
+        assert_eq!(rule.first_graphemes(), Some(vec![String::from("t")]));
+    }
+
+    #[test]
+    fn it_should_report_syntax_error() {
+        assert_eq!(
+            "t -> T".parse::<Rule>(),
+            Err(Error::Syntax {
+                line: 1,
+                message: String::from("unknown sound kind 'T'")
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_report_invalid_rule() {
+        assert_eq!(parse_rule("t Ptk"), Err(String::from("expected '->'")));
+        assert_eq!(
+            parse_rule("t -> Ptk / #"),
+            Err(String::from("expected '_' in the context"))
+        );
+        assert_eq!(
+            parse_rule(" -> Ptk"),
+            Err(String::from("the pattern is empty"))
+        );
+        assert_eq!(
+            parse_rule("t# -> Ptk"),
+            Err(String::from("the pattern contains the word boundary"))
+        );
+        assert_eq!(parse_rule("[pt -> Ptk"), Err(String::from("expected ']'")));
+        assert_eq!(parse_rule("(t -> Ptk"), Err(String::from("expected ')'")));
+        assert_eq!(
+            parse_rule("(t) -> Ptk"),
+            Err(String::from("expected '|' in the alternation"))
+        );
+        assert_eq!(
+            parse_rule("((t|d)|k) -> Ptk"),
+            Err(String::from("nested alternation"))
+        );
+        assert_eq!(
+            parse_rule("(t|#) -> Ptk"),
+            Err(String::from("the pattern contains the word boundary"))
+        );
+        assert_eq!(
+            parse_rule("t -> Ptk \"final"),
+            Err(String::from("expected closing '\"'"))
+        );
+        assert_eq!(
+            parse_rule("t -> T"),
+            Err(String::from("unknown sound kind 'T'"))
+        );
+    }
+}
+
+#[cfg(test)]
+mod rule_set_from_str {
+    use super::{Error, RuleSet};
+
+    #[test]
+    fn it_should_parse_rules() {
+        let rules: RuleSet = "
+            # comment
+
+            ch -> Ch
+            [ptc] -> Ptk / # _
+        "
+        .parse()
+        .unwrap();
+
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules.rules()[1].source(), "[ptc] -> Ptk / # _");
+    }
+
+    #[test]
+    fn it_should_report_line() {
+        assert_eq!(
+            "ch -> Ch\n\nth => Th".parse::<RuleSet>(),
+            Err(Error::Syntax {
+                line: 3,
+                message: String::from("expected '->'")
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_parse_bundled_rules() {
+        assert!(!RuleSet::english().is_empty());
+    }
+
+    #[test]
+    fn it_should_report_missing_file() {
+        assert!(matches!(
+            RuleSet::from_file("missing.rules"),
+            Err(Error::Io(_))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod parse_word {
+    use super::{RuleSet, Sound, SoundKind};
+    use crate::scanner::Scanner;
+
+    fn parse(rules: &str, word: &str) -> Vec<Sound> {
+        rules
+            .parse::<RuleSet>()
+            .unwrap()
+            .parse_word(Scanner::new(word))
+    }
+
+    #[test]
+    fn it_should_match_first_rule() {
+        assert_eq!(
+            parse("th -> Th\nt -> Ptk", "Tht"),
+            vec![
+                Sound::new(SoundKind::Th, String::from("Th")),
+                Sound::new(SoundKind::Ptk, String::from("t")),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_match_boundaries() {
+        assert_eq!(
+            parse("t -> Ptk / _ #", "tat"),
+            vec![
+                Sound::new(SoundKind::Undefined, String::from("t")),
+                Sound::new(SoundKind::Undefined, String::from("a")),
+                Sound::new(SoundKind::Ptk, String::from("t")),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_match_vowels_and_consonants() {
+        assert_eq!(
+            parse("t -> Ptk / V _ C", "atta"),
+            vec![
+                Sound::new(SoundKind::Undefined, String::from("a")),
+                Sound::new(SoundKind::Ptk, String::from("t")),
+                Sound::new(SoundKind::Undefined, String::from("t")),
+                Sound::new(SoundKind::Undefined, String::from("a")),
+            ]
+        );
+        assert_eq!(
+            parse("[^aeiou] -> V / # _", "ava")[0].kind(),
+            &SoundKind::Undefined
+        );
+        assert_eq!(parse("V -> V / # _", "Éva")[0].kind(), &SoundKind::V);
+    }
+
+    #[test]
+    fn it_should_match_longest_alternative() {
+        assert_eq!(
+            parse("(tt|t) -> Flap / V _ (er|y) (|s) #", "butters"),
+            vec![
+                Sound::new(SoundKind::Undefined, String::from("b")),
+                Sound::new(SoundKind::Undefined, String::from("u")),
+                Sound::new(SoundKind::Flap, String::from("tt")),
+                Sound::new(SoundKind::Undefined, String::from("e")),
+                Sound::new(SoundKind::Undefined, String::from("r")),
+                Sound::new(SoundKind::Undefined, String::from("s")),
+            ]
+        );
+        assert_eq!(
+            parse("(tt|t) -> Flap / V _ (er|y) (|s) #", "city")[2].kind(),
+            &SoundKind::Flap
+        );
+        assert_eq!(
+            parse("(tt|t) -> Flap / V _ (er|y) (|s) #", "cityer")[2].kind(),
+            &SoundKind::Undefined
+        );
+    }
+
+    #[test]
+    fn it_should_match_alternation_in_left_context() {
+        assert_eq!(
+            parse("t -> Ptk / (# | s) _", "stat")
+                .iter()
+                .map(|sound| *sound.kind())
+                .collect::<Vec<SoundKind>>(),
+            vec![
+                SoundKind::Undefined,
+                SoundKind::Ptk,
+                SoundKind::Undefined,
+                SoundKind::Undefined
+            ]
+        );
+        assert_eq!(
+            parse("t -> Ptk / (# | s) _", "tat")[0].kind(),
+            &SoundKind::Ptk
+        );
+    }
+}
@@ -1,141 +1,90 @@
-/// The scanner to move through the text
+use crate::options::Options;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The scanner to move through the word by grapheme clusters
 /// Inspired by [Lyn crate](https://crates.io/crates/lyn) and by an [article](https://depth-first.com/articles/2021/12/16/a-beginners-guide-to-parsing-in-rust/)
 #[derive(Debug)]
 pub struct Scanner {
     cursor: usize,
-    characters: Vec<char>,
+    graphemes: Vec<String>,
+    next_word: Vec<String>,
 }
 
-/// Space html character
-const SPACE_HTML_CHAR: &char = &' ';
-
-/// Non-breakable space html character
-const NON_BREAKABLE_SPACE_HTML_CHAR: &char = &'\u{a0}';
-
-/// Default char if Scanner will found nothing
+/// Default grapheme if Scanner will found nothing
 /// Just an easy workaround for Option
-const DEFAULT_CHAR: &char = SPACE_HTML_CHAR;
-
-/// Array of the punctuation characters
-const PUNCTUATION_CHARS: [char; 7] = ['.', ',', ';', '!', '?', ':', '-'];
+const DEFAULT_GRAPHEME: &str = " ";
 
 impl Scanner {
-    /// Creates new Scanner
+    /// Creates new Scanner over the word.
+    /// Word boundaries are found by the tokenizer, so the whole string is treated as one word.
     pub fn new(string: &str) -> Self {
         Self {
             cursor: 0,
-            characters: string.chars().collect(),
+            graphemes: string.graphemes(true).map(String::from).collect(),
+            next_word: vec![],
         }
     }
 
-    /// Returns the current cursor. Useful for reporting errors.
-    pub fn cursor(&self) -> usize {
-        self.cursor
+    /// Creates new Scanner over the NFC normalized word.
+    pub fn normalized(string: &str) -> Self {
+        Self::new(&string.nfc().collect::<String>())
     }
 
-    /// Returns the next character without advancing the cursor.
-    pub fn peek(&self) -> &char {
-        self.characters.get(self.cursor).unwrap_or(DEFAULT_CHAR)
+    /// Creates new Scanner according to the options.
+    pub fn with_options(string: &str, options: &Options) -> Self {
+        match options.nfc {
+            true => Self::normalized(string),
+            false => Self::new(string),
+        }
     }
 
-    /// Returns the next + 1 character without advancing the cursor.
-    pub fn peek_next(&self) -> &char {
-        self.characters.get(self.cursor + 1).unwrap_or(DEFAULT_CHAR)
+    /// Sets the word following the scanned one, rules can look into it.
+    pub fn with_next_word(mut self, word: &str) -> Self {
+        self.next_word = word.graphemes(true).map(String::from).collect();
+        self
     }
 
-    /// Returns the prev character without advancing the cursor.
-    pub fn peek_prev(&self) -> &char {
-        match self.cursor() == 0 {
-            true => DEFAULT_CHAR,
-            false => self.characters.get(self.cursor - 1).unwrap_or(DEFAULT_CHAR),
-        }
+    /// Returns the next grapheme without advancing the cursor.
+    pub fn peek(&self) -> &str {
+        self.get(self.cursor)
     }
 
-    /// Returns true if further progress is not possible.
-    pub fn is_done(&self) -> bool {
-        self.cursor == self.characters.len()
-    }
-
-    /// Returns true if the first char.
-    pub fn is_first(&self) -> bool {
-        match self.cursor == 0 {
-            true => true,
-            false => {
-                let prev_char = self.peek_prev();
+    /// Returns the grapheme at the offset from the cursor or None if it is out of the word.
+    pub fn peek_at(&self, offset: isize) -> Option<&str> {
+        let index = self.cursor.checked_add_signed(offset)?;
 
-                prev_char == SPACE_HTML_CHAR
-                    || prev_char == NON_BREAKABLE_SPACE_HTML_CHAR
-                    || Self::is_punctuation(prev_char)
-            }
-        }
+        self.graphemes.get(index).map(String::as_str)
     }
 
-    /// Returns true if the last char.
-    pub fn is_last(&self) -> bool {
-        match self.cursor + 1 == self.characters.len() {
-            true => true,
-            false => {
-                let next_char = self.peek_next();
-
-                next_char == SPACE_HTML_CHAR
-                    || next_char == NON_BREAKABLE_SPACE_HTML_CHAR
-                    || Self::is_punctuation(next_char)
-            }
-        }
+    /// Returns the grapheme of the next word by the index or None if it is out of the word.
+    pub fn peek_next_word(&self, index: usize) -> Option<&str> {
+        self.next_word.get(index).map(String::as_str)
     }
 
-    // Returns true if next char exists in `chars` param
-    pub fn is_next_any(&self, chars: Vec<char>) -> bool {
-        chars.iter().any(|c| self.peek_next() == c)
+    /// Returns true if further progress is not possible.
+    pub fn is_done(&self) -> bool {
+        self.cursor == self.graphemes.len()
     }
 
-    /// Returns the next character and advances the cursor.
-    pub fn pop(&mut self) -> &char {
-        match self.characters.get(self.cursor) {
-            Some(character) => {
+    /// Returns the next grapheme and advances the cursor.
+    pub fn pop(&mut self) -> &str {
+        match self.graphemes.get(self.cursor) {
+            Some(grapheme) => {
                 self.cursor += 1;
 
-                character
+                grapheme
             }
-            None => DEFAULT_CHAR,
+            None => DEFAULT_GRAPHEME,
         }
     }
 
-    /// Returns true if the character is a punctuation character
-    fn is_punctuation(c: &char) -> bool {
-        PUNCTUATION_CHARS.iter().any(|cc| cc == c)
-    }
-}
-
-#[cfg(test)]
-mod cursor {
-    use super::*;
-
-    #[test]
-    fn empty() {
-        let scanner = Scanner::new("");
-
-        assert_eq!(scanner.cursor(), 0)
-    }
-
-    #[test]
-    fn in_progress() {
-        let mut scanner = Scanner::new("abc");
-
-        scanner.pop();
-
-        assert_eq!(scanner.cursor(), 1);
-    }
-
-    #[test]
-    fn done() {
-        let mut scanner = Scanner::new("abc");
-
-        scanner.pop();
-        scanner.pop();
-        scanner.pop();
-
-        assert_eq!(scanner.cursor(), 3)
+    /// Returns the grapheme by the index or the default one
+    fn get(&self, index: usize) -> &str {
+        self.graphemes
+            .get(index)
+            .map(String::as_str)
+            .unwrap_or(DEFAULT_GRAPHEME)
     }
 }
 
@@ -179,7 +128,7 @@ mod peek {
     fn empty() {
         let scanner = Scanner::new("");
 
-        assert_eq!(scanner.peek(), DEFAULT_CHAR)
+        assert_eq!(scanner.peek(), DEFAULT_GRAPHEME)
     }
 
     #[test]
@@ -188,196 +137,147 @@ mod peek {
 
         scanner.pop();
 
-        assert_eq!(scanner.peek(), &'b')
+        assert_eq!(scanner.peek(), "b")
     }
 }
 
 #[cfg(test)]
-mod peek_next {
+mod peek_at {
     use super::*;
 
     #[test]
     fn empty() {
         let scanner = Scanner::new("");
 
-        assert_eq!(scanner.peek_next(), DEFAULT_CHAR)
+        assert_eq!(scanner.peek_at(0), None)
     }
 
     #[test]
-    fn not_done() {
+    fn around_cursor() {
         let mut scanner = Scanner::new("abc");
 
         scanner.pop();
 
-        assert_eq!(scanner.peek_next(), &'c')
+        assert_eq!(scanner.peek_at(-1), Some("a"));
+        assert_eq!(scanner.peek_at(1), Some("c"));
+        assert_eq!(scanner.peek_at(-2), None);
+        assert_eq!(scanner.peek_at(2), None)
     }
 }
 
 #[cfg(test)]
-mod peek_prev {
+mod peek_next_word {
     use super::*;
 
     #[test]
-    fn empty() {
-        let scanner = Scanner::new("");
+    fn without_next_word() {
+        let scanner = Scanner::new("lot");
 
-        assert_eq!(scanner.peek_prev(), DEFAULT_CHAR)
+        assert_eq!(scanner.peek_next_word(0), None)
     }
 
     #[test]
-    fn not_done() {
-        let mut scanner = Scanner::new("abc");
-
-        scanner.pop();
+    fn with_next_word() {
+        let scanner = Scanner::new("lot").with_next_word("of");
 
-        assert_eq!(scanner.peek_prev(), &'a')
+        assert_eq!(scanner.peek_next_word(1), Some("f"));
+        assert_eq!(scanner.peek_next_word(2), None)
     }
 }
 
 #[cfg(test)]
-mod is_first {
+mod pop {
     use super::*;
 
     #[test]
-    fn is_first() {
-        let scanner = Scanner::new("abc");
-
-        assert!(scanner.is_first())
-    }
-
-    #[test]
-    fn is_first_with_punctuation_char() {
-        let mut scanner = Scanner::new("!abc");
-
-        scanner.pop();
+    fn empty() {
+        let mut scanner = Scanner::new("");
 
-        assert!(scanner.is_first())
+        assert_eq!(scanner.pop(), DEFAULT_GRAPHEME);
+        assert_eq!(scanner.cursor, 0)
     }
 
     #[test]
-    fn is_first_with_non_breakable_char() {
-        let mut scanner = Scanner::new("\u{a0}abc");
-
-        scanner.pop();
+    fn not_done() {
+        let mut scanner = Scanner::new("abc");
 
-        assert!(scanner.is_first())
+        assert_eq!(scanner.pop(), "a");
+        assert_eq!(scanner.cursor, 1)
     }
 
     #[test]
-    fn not_is_first() {
+    fn done() {
         let mut scanner = Scanner::new("abc");
 
+        scanner.pop();
+        scanner.pop();
         scanner.pop();
 
-        assert!(!scanner.is_first())
+        assert_eq!(scanner.pop(), DEFAULT_GRAPHEME);
+        assert_eq!(scanner.cursor, 3)
     }
 }
 
 #[cfg(test)]
-mod is_last {
+mod graphemes {
     use super::*;
 
     #[test]
-    fn is_last() {
-        let mut scanner = Scanner::new("abc");
-
-        scanner.pop();
-        scanner.pop();
-
-        assert!(scanner.is_last())
-    }
-
-    #[test]
-    fn is_last_with_punctuation_char() {
-        let mut scanner = Scanner::new("abc!");
+    fn combining_accent() {
+        let mut scanner = Scanner::new("cafe\u{301}");
 
         scanner.pop();
-        scanner.pop();
-
-        assert!(scanner.is_last())
-    }
-
-    #[test]
-    fn is_last_with_non_breakable_char() {
-        let mut scanner = Scanner::new("abc\u{a0}");
-
         scanner.pop();
         scanner.pop();
 
-        assert!(scanner.is_last())
-    }
-
-    #[test]
-    fn not_is_last() {
-        let scanner = Scanner::new("abc");
-
-        assert!(!scanner.is_last())
+        assert_eq!(scanner.peek(), "e\u{301}");
+        assert_eq!(scanner.peek_at(1), None)
     }
-}
-
-#[cfg(test)]
-mod is_next_any {
-    use super::*;
 
     #[test]
-    fn it_should_be_true() {
-        let scanner = Scanner::new("cheese");
+    fn emoji_zwj_sequence() {
+        let mut scanner = Scanner::new("👨\u{200d}👩\u{200d}👧a");
 
-        assert!(scanner.is_next_any(vec!['h']));
+        assert_eq!(scanner.pop(), "👨\u{200d}👩\u{200d}👧");
+        assert_eq!(scanner.pop(), "a");
+        assert!(scanner.is_done())
     }
 
     #[test]
-    fn it_should_be_false() {
-        let scanner = Scanner::new("cheese");
+    fn flag_emoji() {
+        let scanner = Scanner::new("🇬🇧🇺🇸");
 
-        assert!(!scanner.is_next_any(vec!['c']));
+        assert_eq!(scanner.peek(), "🇬🇧");
+        assert_eq!(scanner.peek_at(1), Some("🇺🇸"))
     }
 }
 
 #[cfg(test)]
-mod pop {
+mod normalized {
     use super::*;
 
     #[test]
-    fn empty() {
-        let mut scanner = Scanner::new("");
-
-        assert_eq!(scanner.pop(), DEFAULT_CHAR);
-        assert_eq!(scanner.cursor(), 0)
-    }
-
-    #[test]
-    fn not_done() {
-        let mut scanner = Scanner::new("abc");
-
-        assert_eq!(scanner.pop(), &'a');
-        assert_eq!(scanner.cursor(), 1)
-    }
-
-    #[test]
-    fn done() {
-        let mut scanner = Scanner::new("abc");
+    fn composes_combining_accent() {
+        let mut scanner = Scanner::normalized("cafe\u{301}");
 
         scanner.pop();
         scanner.pop();
         scanner.pop();
 
-        assert_eq!(scanner.pop(), DEFAULT_CHAR);
-        assert_eq!(scanner.cursor(), 3)
-    }
-}
-
-#[cfg(test)]
-mod is_punctuation {
-    use super::*;
-
-    #[test]
-    fn is_punctuation() {
-        assert!(Scanner::is_punctuation(&'!'));
+        assert_eq!(scanner.pop(), "\u{e9}");
+        assert!(scanner.is_done())
     }
 
     #[test]
-    fn is_not_punctuation() {
-        assert!(!Scanner::is_punctuation(&'k'));
+    fn with_options() {
+        let scanner = Scanner::with_options(
+            "e\u{301}",
+            &Options {
+                nfc: true,
+                ..Options::default()
+            },
+        );
+
+        assert_eq!(scanner.peek(), "\u{e9}")
     }
 }
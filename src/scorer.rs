@@ -0,0 +1,446 @@
+use crate::options::Options;
+use crate::parser::parse_paragraphs_with;
+use crate::sound::{Sound, SoundKind};
+use crate::word::Word;
+use std::ops::Range;
+
+/// Letters of the vowels, sounds of other letters are consonants
+const VOWELS: [char; 6] = ['a', 'e', 'i', 'o', 'u', 'y'];
+
+/// Spellings with rare grapheme-phoneme mappings, `#` is the word boundary.
+/// Longer spellings go first, so "ough" is not counted as "gh" too
+const RARE_SPELLINGS: [&str; 14] = [
+    "ough", "augh", "eigh", "#kn", "#wr", "#gn", "#ps", "#pn", "#rh", "mb#", "gn#", "gh", "ph",
+    "eau",
+];
+
+/// Difficulty of every consonant sound after the first one in a cluster
+const CLUSTER_WEIGHT: f64 = 0.5;
+
+/// Difficulty of every rare spelling
+const RARE_SPELLING_WEIGHT: f64 = 1.0;
+
+/// Difficulty of the sound without the profile of the learners
+const SOUND_WEIGHT: f64 = 1.0;
+
+/// Pronunciation difficulty of the word, the sentence or the whole text
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Difficulty {
+    words: usize,
+    sounds: f64,
+    clusters: f64,
+    rare_spellings: f64,
+    final_consonants: f64,
+    kinds: Vec<(SoundKind, f64)>,
+}
+
+impl Difficulty {
+    /// Returns the total score: difficulty of the sounds, the clusters and the rare spellings
+    pub fn score(&self) -> f64 {
+        self.sounds + self.clusters + self.rare_spellings
+    }
+
+    /// Returns the score per word, useful to compare texts of different length
+    pub fn average(&self) -> f64 {
+        match self.words {
+            0 => 0.0,
+            words => self.score() / words as f64,
+        }
+    }
+
+    /// Returns number of the scored words
+    pub fn words(&self) -> usize {
+        self.words
+    }
+
+    /// Returns difficulty of the sounds, weighted by the profile of the learners if any
+    pub fn sounds(&self) -> f64 {
+        self.sounds
+    }
+
+    /// Returns difficulty of the consonant clusters, e.g. "str" in "strong"
+    pub fn clusters(&self) -> f64 {
+        self.clusters
+    }
+
+    /// Returns difficulty of the rare grapheme-phoneme mappings, e.g. "ough" in "thought"
+    pub fn rare_spellings(&self) -> f64 {
+        self.rare_spellings
+    }
+
+    /// Returns difficulty of the final consonants weighted by the profile of the learners,
+    /// it is a part of the difficulty of the sounds
+    pub fn final_consonants(&self) -> f64 {
+        self.final_consonants
+    }
+
+    /// Returns difficulty of the sounds per kind in order of appearance
+    pub fn kinds(&self) -> &[(SoundKind, f64)] {
+        &self.kinds
+    }
+
+    /// Returns difficulty of the sounds of the kind
+    pub fn kind(&self, kind: SoundKind) -> f64 {
+        self.kinds
+            .iter()
+            .find(|(scored, _)| *scored == kind)
+            .map_or(0.0, |(_, score)| *score)
+    }
+
+    fn add_sound(&mut self, kind: SoundKind, score: f64) {
+        self.sounds += score;
+
+        match self.kinds.iter_mut().find(|(scored, _)| *scored == kind) {
+            Some((_, total)) => *total += score,
+            None => self.kinds.push((kind, score)),
+        }
+    }
+
+    fn add_final_consonant(&mut self, score: f64) {
+        self.sounds += score;
+        self.final_consonants += score;
+    }
+
+    fn add(&mut self, other: &Difficulty) {
+        self.words += other.words;
+        self.clusters += other.clusters;
+        self.rare_spellings += other.rare_spellings;
+        self.add_final_consonant(other.final_consonants);
+
+        for (kind, score) in &other.kinds {
+            self.add_sound(*kind, *score);
+        }
+    }
+}
+
+/// Text of the word or the sentence with its difficulty
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scored {
+    text: String,
+    span: Range<usize>,
+    difficulty: Difficulty,
+}
+
+impl Scored {
+    /// Returns source text of the word or the sentence
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns byte span in the source text
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Returns difficulty of the word or the sentence
+    pub fn difficulty(&self) -> &Difficulty {
+        &self.difficulty
+    }
+}
+
+/// Difficulty of the whole text with its sentences and words
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextDifficulty {
+    difficulty: Difficulty,
+    sentences: Vec<Scored>,
+    words: Vec<Scored>,
+}
+
+impl TextDifficulty {
+    /// Returns difficulty of the whole text
+    pub fn difficulty(&self) -> &Difficulty {
+        &self.difficulty
+    }
+
+    /// Returns sentences of the text with their difficulty
+    pub fn sentences(&self) -> &[Scored] {
+        &self.sentences
+    }
+
+    /// Returns words of the text with their difficulty in order of the text
+    pub fn words(&self) -> &[Scored] {
+        &self.words
+    }
+
+    /// Returns unique words from the hardest to the easiest, words with the same score keep the order
+    pub fn ranking(&self) -> Vec<&Scored> {
+        let mut ranking: Vec<&Scored> = vec![];
+
+        for word in &self.words {
+            let is_ranked = ranking
+                .iter()
+                .any(|ranked| ranked.text.to_lowercase() == word.text.to_lowercase());
+
+            if !is_ranked {
+                ranking.push(word);
+            }
+        }
+
+        ranking.sort_by(|a, b| b.difficulty.score().total_cmp(&a.difficulty.score()));
+
+        ranking
+    }
+}
+
+/// Score pronunciation difficulty of the text by its sounds, consonant clusters and rare spellings
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::{score, SoundKind};
+///
+/// let difficulty = score("The thought");
+///
+/// assert_eq!(difficulty.difficulty().kind(SoundKind::Th), 2.0);
+/// assert_eq!(difficulty.ranking()[0].text(), "thought");
+/// assert_eq!(difficulty.ranking()[0].difficulty().rare_spellings(), 1.0);
+/// ```
+pub fn score<T: AsRef<str>>(text: T) -> TextDifficulty {
+    score_with(text, &Options::default())
+}
+
+/// Score pronunciation difficulty of the text with the options,
+/// sounds are weighted by the profile of the learners if any
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::{score, score_with, Options, Profile, SoundKind};
+///
+/// let options = Options {
+///     profile: Some(Profile::bundled("es").unwrap()),
+///     ..Options::default()
+/// };
+///
+/// assert_eq!(score("very bad").difficulty().sounds(), 1.0);
+/// assert_eq!(score_with("very bad", &options).difficulty().sounds(), 3.0);
+/// ```
+pub fn score_with<T: AsRef<str>>(text: T, options: &Options) -> TextDifficulty {
+    let mut text_difficulty = TextDifficulty::default();
+
+    for paragraph in parse_paragraphs_with(text, options) {
+        for sentence in paragraph.sentences() {
+            let mut sentence_difficulty = Difficulty::default();
+
+            for word in sentence.words().iter().filter(|word| !word.is_separator()) {
+                let difficulty = score_word(word, options);
+
+                sentence_difficulty.add(&difficulty);
+
+                text_difficulty.words.push(Scored {
+                    text: word.text().to_string(),
+                    span: word.span(),
+                    difficulty,
+                });
+            }
+
+            text_difficulty.difficulty.add(&sentence_difficulty);
+            text_difficulty.sentences.push(Scored {
+                text: sentence.text().to_string(),
+                span: sentence.span(),
+                difficulty: sentence_difficulty,
+            });
+        }
+    }
+
+    text_difficulty
+}
+
+pub(crate) fn score_word(word: &Word, options: &Options) -> Difficulty {
+    let mut difficulty = Difficulty {
+        words: 1,
+        ..Difficulty::default()
+    };
+
+    for sound in word.sounds() {
+        let weight = match (&options.profile, sound.kind()) {
+            // the final consonant of the kind missed in the profile is weighted by its position
+            (Some(profile), kind) if sound.is_final() && !profile.is_highlighted(*kind) => {
+                difficulty.add_final_consonant(profile.final_weight());
+
+                continue;
+            }
+            (_, SoundKind::Undefined) => continue,
+            (Some(profile), kind) => profile.weight(*kind),
+            (None, _) => SOUND_WEIGHT,
+        };
+
+        if weight > 0.0 {
+            difficulty.add_sound(*sound.kind(), weight);
+        }
+    }
+
+    difficulty.clusters = count_clustered_consonants(word.sounds()) as f64 * CLUSTER_WEIGHT;
+
+    // the reader does not see the spelling of the expanded word, e.g. "third" of "3rd"
+    if !word.is_expanded() {
+        difficulty.rare_spellings =
+            count_rare_spellings(word.normalized()) as f64 * RARE_SPELLING_WEIGHT;
+    }
+
+    difficulty
+}
+
+/// Returns number of the consonant sounds after the first one in the clusters, digraphs are one sound
+fn count_clustered_consonants(sounds: &[Sound]) -> usize {
+    let mut count = 0;
+    let mut run = 0;
+
+    for sound in sounds {
+        let is_consonant = !sound.text().is_empty()
+            && sound
+                .text()
+                .chars()
+                .all(|c| c.is_ascii_alphabetic() && !VOWELS.contains(&c.to_ascii_lowercase()));
+
+        run = match is_consonant {
+            true => run + 1,
+            false => 0,
+        };
+
+        if run > 1 {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+/// Returns number of the rare spellings in the word
+fn count_rare_spellings(word: &str) -> usize {
+    let marked = format!("#{}#", word);
+    let mut rest = marked.as_str();
+    let mut count = 0;
+
+    while let Some(c) = rest.chars().next() {
+        match RARE_SPELLINGS
+            .iter()
+            .find(|spelling| rest.starts_with(*spelling))
+        {
+            Some(spelling) => {
+                count += 1;
+                rest = &rest[spelling.len()..];
+            }
+            None => rest = &rest[c.len_utf8()..],
+        }
+    }
+
+    count
+}
+
+#[cfg(test)]
+mod score {
+    use super::{count_rare_spellings, score, SoundKind};
+
+    #[test]
+    fn it_should_score_empty() {
+        let difficulty = score("");
+
+        assert_eq!(difficulty.difficulty().score(), 0.0);
+        assert_eq!(difficulty.difficulty().average(), 0.0);
+        assert!(difficulty.words().is_empty());
+    }
+
+    #[test]
+    fn it_should_score_sounds_and_clusters() {
+        let difficulty = score("strengths");
+        let word = difficulty.words()[0].difficulty();
+
+        // s t r e ng th s
+        assert_eq!(word.sounds(), 2.0);
+        assert_eq!(word.clusters(), 2.0);
+        assert_eq!(word.rare_spellings(), 0.0);
+        assert_eq!(
+            word.kinds(),
+            &[(SoundKind::Ng, 1.0), (SoundKind::Th, 1.0)][..]
+        );
+    }
+
+    #[test]
+    fn it_should_count_rare_spellings() {
+        assert_eq!(count_rare_spellings("though"), 1);
+        assert_eq!(count_rare_spellings("knight"), 2);
+        assert_eq!(count_rare_spellings("lamb"), 1);
+        assert_eq!(count_rare_spellings("bombing"), 0);
+        assert_eq!(count_rare_spellings("photograph"), 2);
+    }
+
+    #[test]
+    fn it_should_score_sentences_and_text() {
+        let difficulty = score("I see. The thought strengths!");
+
+        assert_eq!(difficulty.sentences().len(), 2);
+        assert_eq!(difficulty.sentences()[0].difficulty().score(), 0.0);
+        assert_eq!(difficulty.sentences()[1].text(), "The thought strengths!");
+        assert_eq!(
+            difficulty.difficulty().score(),
+            difficulty.sentences()[1].difficulty().score()
+        );
+        assert_eq!(difficulty.difficulty().words(), 5);
+    }
+
+    #[test]
+    fn it_should_rank_unique_words() {
+        let difficulty = score("The cat, the thought");
+
+        let ranking = difficulty
+            .ranking()
+            .iter()
+            .map(|word| word.text())
+            .collect::<Vec<&str>>();
+
+        assert_eq!(ranking, vec!["thought", "cat", "The"]);
+    }
+}
+
+#[cfg(test)]
+mod score_with {
+    use super::{score, score_with, Options, SoundKind};
+    use crate::profile::Profile;
+
+    fn profile(code: &str) -> Options {
+        Options {
+            profile: Some(Profile::bundled(code).unwrap()),
+            ..Options::default()
+        }
+    }
+
+    #[test]
+    fn it_should_weight_sounds_by_profile() {
+        let difficulty = score_with("the rule", &profile("ja"));
+
+        assert_eq!(difficulty.difficulty().kind(SoundKind::Th), 1.0);
+        assert_eq!(difficulty.difficulty().kind(SoundKind::R), 1.5);
+        assert_eq!(difficulty.difficulty().kind(SoundKind::L), 1.5);
+        assert_eq!(difficulty.difficulty().sounds(), 4.0);
+    }
+
+    #[test]
+    fn it_should_skip_sounds_missed_in_profile() {
+        assert_eq!(score("cat").difficulty().sounds(), 2.0);
+        assert_eq!(score_with("cat", &profile("ja")).difficulty().sounds(), 0.0);
+    }
+
+    #[test]
+    fn it_should_weight_final_consonants_by_profile() {
+        let difficulty = score_with("Big dogs", &profile("zh"));
+
+        assert_eq!(difficulty.difficulty().final_consonants(), 4.5);
+        assert_eq!(difficulty.difficulty().sounds(), 4.5);
+        assert_eq!(difficulty.words()[1].difficulty().final_consonants(), 3.0);
+        assert_eq!(score("Big dogs").difficulty().final_consonants(), 0.0);
+    }
+
+    #[test]
+    fn it_should_not_score_spelling_of_expanded_words() {
+        let options = Options {
+            expand: true,
+            ..Options::default()
+        };
+
+        let difficulty = score_with("8", &options);
+
+        assert_eq!(difficulty.words()[0].text(), "8");
+        assert_eq!(difficulty.words()[0].difficulty().rare_spellings(), 0.0);
+    }
+}
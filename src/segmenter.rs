@@ -0,0 +1,315 @@
+use crate::word::Word;
+use std::ops::Range;
+
+/// Characters that always end a sentence
+const SENTENCE_END_CHARS: [char; 4] = ['!', '?', '…', '。'];
+
+/// Character that ends a sentence if the previous word is not an abbreviation
+const FULL_STOP_CHAR: char = '.';
+
+/// Common abbreviations followed by a full stop, which do not end a sentence
+const ABBREVIATIONS: [&str; 24] = [
+    "mr", "mrs", "ms", "dr", "prof", "sr", "jr", "mt", "rev", "gen", "col", "capt", "lt", "sgt",
+    "gov", "sen", "vs", "etc", "cf", "approx", "vol", "inc", "ltd", "corp",
+];
+
+/// Abbreviations which are also common words, they do not end a sentence only before a number, e.g. "No. 5"
+const NUMBER_ABBREVIATIONS: [&str; 2] = ["no", "fig"];
+
+/// Abbreviations which are also common words, they do not end a sentence only before a name, e.g. "St. Louis"
+const NAME_ABBREVIATIONS: [&str; 2] = ["st", "rep"];
+
+/// Struct of the sentence with its words and the separators after it
+#[derive(Debug, PartialEq)]
+pub struct Sentence {
+    text: String,
+    span: Range<usize>,
+    words: Vec<Word>,
+}
+
+impl Sentence {
+    fn new(words: Vec<Word>) -> Self {
+        Self {
+            text: words.iter().map(Word::text).collect(),
+            span: span_of(words.iter().map(Word::span)),
+            words,
+        }
+    }
+
+    /// Returns source text of the sentence
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns byte span of the sentence in the source text
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Returns words of the sentence
+    pub fn words(&self) -> &[Word] {
+        &self.words
+    }
+}
+
+/// Struct of the paragraph with its sentences
+#[derive(Debug, PartialEq)]
+pub struct Paragraph {
+    text: String,
+    span: Range<usize>,
+    sentences: Vec<Sentence>,
+}
+
+impl Paragraph {
+    fn new(sentences: Vec<Sentence>) -> Self {
+        Self {
+            text: sentences.iter().map(Sentence::text).collect(),
+            span: span_of(sentences.iter().map(Sentence::span)),
+            sentences,
+        }
+    }
+
+    /// Returns source text of the paragraph
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns byte span of the paragraph in the source text
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Returns sentences of the paragraph
+    pub fn sentences(&self) -> &[Sentence] {
+        &self.sentences
+    }
+}
+
+/// Group words to sentences and paragraphs.
+/// Separators after the sentence end stay in the sentence, a blank line ends the paragraph.
+pub fn segment(words: Vec<Word>) -> Vec<Paragraph> {
+    let mut paragraphs = vec![];
+    let mut sentences = vec![];
+    let mut current: Vec<Word> = vec![];
+
+    let mut is_sentence_ended = false;
+    let mut line_breaks = 0;
+
+    for word in words {
+        if word.is_separator() {
+            line_breaks += count_line_breaks(word.text());
+            is_sentence_ended = is_sentence_ended || is_sentence_end(&current, &word);
+
+            current.push(word);
+
+            continue;
+        }
+
+        is_sentence_ended = is_sentence_ended || is_ambiguous_end(&current, &word);
+
+        let is_paragraph_ended = line_breaks > 1;
+
+        if (is_sentence_ended || is_paragraph_ended) && !current.is_empty() {
+            sentences.push(Sentence::new(std::mem::take(&mut current)));
+        }
+
+        if is_paragraph_ended && !sentences.is_empty() {
+            paragraphs.push(Paragraph::new(std::mem::take(&mut sentences)));
+        }
+
+        is_sentence_ended = false;
+        line_breaks = 0;
+
+        current.push(word);
+    }
+
+    if !current.is_empty() {
+        sentences.push(Sentence::new(current));
+    }
+
+    if !sentences.is_empty() {
+        paragraphs.push(Paragraph::new(sentences));
+    }
+
+    paragraphs
+}
+
+/// Returns true if the separator ends the sentence
+fn is_sentence_end(current: &[Word], separator: &Word) -> bool {
+    let text = separator.text();
+
+    if text.chars().any(|c| SENTENCE_END_CHARS.contains(&c)) {
+        return true;
+    }
+
+    if !text.starts_with(FULL_STOP_CHAR) {
+        return false;
+    }
+
+    match current.last() {
+        Some(word) if !word.is_separator() => !is_abbreviation(word),
+        _ => true,
+    }
+}
+
+/// Returns true if the word followed by a full stop is an abbreviation or an initial.
+/// The ambiguous abbreviations are checked by the next word in `is_ambiguous_end`.
+fn is_abbreviation(word: &Word) -> bool {
+    let normalized = word.normalized();
+
+    let is_initial =
+        word.text().chars().count() == 1 && word.text().chars().all(char::is_uppercase);
+    let is_dotted =
+        normalized.contains(FULL_STOP_CHAR) && normalized.chars().any(char::is_alphabetic);
+
+    is_initial
+        || is_dotted
+        || ABBREVIATIONS.contains(&normalized)
+        || NUMBER_ABBREVIATIONS.contains(&normalized)
+        || NAME_ABBREVIATIONS.contains(&normalized)
+}
+
+/// Returns true if the full stop after the ambiguous abbreviation ends the sentence before the next word,
+/// e.g. "The answer is no. We left." is two sentences, but "No. 5 is here." is one
+fn is_ambiguous_end(current: &[Word], next: &Word) -> bool {
+    let Some(index) = current.iter().rposition(|word| !word.is_separator()) else {
+        return false;
+    };
+
+    match current.get(index + 1) {
+        Some(separator) if separator.text().starts_with(FULL_STOP_CHAR) => (),
+        _ => return false,
+    }
+
+    let normalized = current[index].normalized();
+    let first = next.text().chars().next();
+
+    if NUMBER_ABBREVIATIONS.contains(&normalized) {
+        return !first.is_some_and(|c| c.is_ascii_digit());
+    }
+
+    if NAME_ABBREVIATIONS.contains(&normalized) {
+        return !first.is_some_and(char::is_uppercase);
+    }
+
+    false
+}
+
+/// Returns count of the line breaks, `\r\n` is one line break
+fn count_line_breaks(text: &str) -> usize {
+    text.replace("\r\n", "\n")
+        .chars()
+        .filter(|c| matches!(c, '\n' | '\r' | '\u{2028}' | '\u{2029}'))
+        .count()
+}
+
+/// Returns span from the start of the first span to the end of the last one
+fn span_of<I: Iterator<Item = Range<usize>>>(mut spans: I) -> Range<usize> {
+    let first = spans.next().unwrap_or(0..0);
+    let last = spans.last().unwrap_or_else(|| first.clone());
+
+    first.start..last.end
+}
+
+#[cfg(test)]
+mod segment {
+    use crate::parser::{parse_paragraphs, parse_words};
+    use crate::serializer::serialize;
+
+    fn sentences(text: &str) -> Vec<String> {
+        parse_paragraphs(text)
+            .iter()
+            .flat_map(|paragraph| paragraph.sentences())
+            .map(|sentence| sentence.text().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn it_should_segment_empty() {
+        assert!(parse_paragraphs("").is_empty());
+    }
+
+    #[test]
+    fn it_should_segment_sentences() {
+        assert_eq!(
+            sentences("Put it there. What? Yes! Thanks…Bye"),
+            vec!["Put it there. ", "What? ", "Yes! ", "Thanks…", "Bye"]
+        );
+    }
+
+    #[test]
+    fn it_should_keep_closing_quotes_in_sentence() {
+        assert_eq!(
+            sentences("He said \"stop.\" Then he left."),
+            vec!["He said \"stop.\" ", "Then he left."]
+        );
+    }
+
+    #[test]
+    fn it_should_not_end_sentence_after_abbreviation() {
+        assert_eq!(
+            sentences("Dr. Smith met Mrs. Jones, e.g. at St. Paul's. It was 3.14 p.m. there."),
+            vec![
+                "Dr. Smith met Mrs. Jones, e.g. at St. Paul's. ",
+                "It was 3.14 p.m. there."
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_end_sentence_after_ambiguous_abbreviation() {
+        assert_eq!(
+            sentences("The answer is no. We left."),
+            vec!["The answer is no. ", "We left."]
+        );
+        assert_eq!(
+            sentences("Go to St. Louis and see No. 5 there. It is on the 1st st. next door."),
+            vec![
+                "Go to St. Louis and see No. 5 there. ",
+                "It is on the 1st st. ",
+                "next door."
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_not_end_sentence_after_initial() {
+        assert_eq!(
+            sentences("J. R. R. Tolkien wrote it. We read it."),
+            vec!["J. R. R. Tolkien wrote it. ", "We read it."]
+        );
+    }
+
+    #[test]
+    fn it_should_segment_paragraphs() {
+        let paragraphs = parse_paragraphs("Title\n\nFirst one. Second one.\r\n\r\nLast\none");
+
+        let texts = paragraphs
+            .iter()
+            .map(|paragraph| paragraph.text())
+            .collect::<Vec<&str>>();
+
+        assert_eq!(
+            texts,
+            vec!["Title\n\n", "First one. Second one.\r\n\r\n", "Last\none"]
+        );
+        assert_eq!(paragraphs[1].sentences().len(), 2);
+        assert_eq!(paragraphs[1].span(), 7..33);
+        assert_eq!(paragraphs[1].sentences()[1].span(), 18..33);
+    }
+
+    #[test]
+    fn it_should_keep_leading_separators() {
+        assert_eq!(sentences("  \"Go.\" "), vec!["  \"Go.\" "]);
+    }
+
+    #[test]
+    fn it_should_segment_losslessly() {
+        let text = "«Dr. Who?» — he asked.\n\n\tNo idea… really!";
+
+        let paragraphs = parse_paragraphs(text);
+
+        assert_eq!(serialize(paragraphs), text);
+        assert_eq!(serialize(parse_words(text)), text);
+    }
+}
@@ -1,6 +1,38 @@
+use crate::segmenter::{Paragraph, Sentence};
 use crate::sound::Sound;
+use crate::word::Word;
 
-/// Serialize sounds to text
+/// Items that can be serialized back to the text
+pub trait Serializable {
+    /// Returns source text of the item
+    fn source_text(&self) -> &str;
+}
+
+impl Serializable for Sound {
+    fn source_text(&self) -> &str {
+        self.text()
+    }
+}
+
+impl Serializable for Word {
+    fn source_text(&self) -> &str {
+        self.text()
+    }
+}
+
+impl Serializable for Sentence {
+    fn source_text(&self) -> &str {
+        self.text()
+    }
+}
+
+impl Serializable for Paragraph {
+    fn source_text(&self) -> &str {
+        self.text()
+    }
+}
+
+/// Serialize sounds (or words) to text
 ///
 /// ## Example
 ///
@@ -32,14 +64,14 @@ use crate::sound::Sound;
 ///
 /// assert_eq!(serialize(sounds), "The text just in case");
 /// ```
-pub fn serialize(sounds: Vec<Sound>) -> String {
-    if sounds.is_empty() {
+pub fn serialize<T: Serializable>(items: Vec<T>) -> String {
+    if items.is_empty() {
         return String::from("");
     }
 
-    sounds
+    items
         .iter()
-        .fold(String::new(), |string, sound| string + sound.text())
+        .fold(String::new(), |string, item| string + item.source_text())
 }
 
 #[cfg(test)]
@@ -49,7 +81,7 @@ mod serialize {
 
     #[test]
     fn it_should_serialize_empty() {
-        assert_eq!(serialize(Vec::new()), String::from(""));
+        assert_eq!(serialize(Vec::<Sound>::new()), String::from(""));
     }
 
     #[test]
@@ -75,3 +107,23 @@ mod serialize {
         assert_eq!(serialize(sounds), "Then PuT tOgETHer");
     }
 }
+
+#[cfg(test)]
+mod serialize_words {
+    use super::serialize;
+    use crate::parser::parse_words;
+    use crate::word::Word;
+
+    #[test]
+    fn it_should_serialize_empty_words() {
+        assert_eq!(serialize(Vec::<Word>::new()), String::from(""));
+    }
+
+    #[test]
+    fn it_should_serialize_words() {
+        assert_eq!(
+            serialize(parse_words("Then PuT tOgETHer!")),
+            "Then PuT tOgETHer!"
+        );
+    }
+}
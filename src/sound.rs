@@ -1,7 +1,10 @@
+use crate::error::Error;
+use std::str::FromStr;
+use unicode_normalization::UnicodeNormalization;
 use uuid::Uuid;
 
-/// English sound kinds
-#[derive(Debug, PartialEq)]
+/// Sound kinds of the languages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SoundKind {
     Ptk,
     Th,
@@ -10,9 +13,163 @@ pub enum SoundKind {
     Ng,
     Ch,
     Dj,
+    /// Flapped t, e.g. in "butter" in General American
+    Flap,
+    /// Glottal stop, e.g. in "button" in Received Pronunciation
+    GlottalStop,
+    /// German ich-Laut /ç/, e.g. in "ich"
+    Ich,
+    /// German ach-Laut /x/, e.g. in "Bach"
+    Ach,
+    /// German final devoicing of b, d and g, e.g. in "Hund"
+    Devoiced,
+    /// B merged with V, e.g. by Spanish speakers
+    B,
+    /// R merged with L, e.g. by Japanese speakers
+    R,
+    /// L merged with R, e.g. by Japanese speakers
+    L,
     Undefined,
 }
 
+impl FromStr for SoundKind {
+    type Err = Error;
+
+    /// Parses the sound kind from its name, e.g. "Ptk"
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Ptk" => Ok(SoundKind::Ptk),
+            "Th" => Ok(SoundKind::Th),
+            "W" => Ok(SoundKind::W),
+            "V" => Ok(SoundKind::V),
+            "Ng" => Ok(SoundKind::Ng),
+            "Ch" => Ok(SoundKind::Ch),
+            "Dj" => Ok(SoundKind::Dj),
+            "Flap" => Ok(SoundKind::Flap),
+            "GlottalStop" => Ok(SoundKind::GlottalStop),
+            "Ich" => Ok(SoundKind::Ich),
+            "Ach" => Ok(SoundKind::Ach),
+            "Devoiced" => Ok(SoundKind::Devoiced),
+            "B" => Ok(SoundKind::B),
+            "R" => Ok(SoundKind::R),
+            "L" => Ok(SoundKind::L),
+            "Undefined" => Ok(SoundKind::Undefined),
+            _ => Err(Error::UnknownSoundKind(name.to_string())),
+        }
+    }
+}
+
+/// Voicing of the sound
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Voicing {
+    Voiced,
+    Voiceless,
+}
+
+/// Place of articulation of the sound, vowels have their own place
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Place {
+    Bilabial,
+    Labiodental,
+    Dental,
+    Alveolar,
+    Postalveolar,
+    Palatal,
+    Velar,
+    Glottal,
+    Vowel,
+}
+
+/// Manner of articulation of the sound
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Manner {
+    Stop,
+    Fricative,
+    Affricate,
+    Nasal,
+    Approximant,
+    Flap,
+    Vowel,
+}
+
+/// Articulatory features of the sound: voicing, place and manner
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Features {
+    pub voicing: Voicing,
+    pub place: Place,
+    pub manner: Manner,
+}
+
+impl Features {
+    const fn new(voicing: Voicing, place: Place, manner: Manner) -> Self {
+        Self {
+            voicing,
+            place,
+            manner,
+        }
+    }
+
+    /// Returns features of the sound usually spelled by the letter, e.g. voiced bilabial stop for "b"
+    fn of_letter(letter: char) -> Option<Self> {
+        use Manner::*;
+        use Place::*;
+        use Voicing::*;
+
+        let features = match letter.to_ascii_lowercase() {
+            'a' | 'e' | 'i' | 'o' | 'u' | 'y' => Features::new(Voiced, Place::Vowel, Manner::Vowel),
+            'b' => Features::new(Voiced, Bilabial, Stop),
+            'c' | 'k' | 'q' | 'x' => Features::new(Voiceless, Velar, Stop),
+            'd' => Features::new(Voiced, Alveolar, Stop),
+            'f' => Features::new(Voiceless, Labiodental, Fricative),
+            'g' => Features::new(Voiced, Velar, Stop),
+            'h' => Features::new(Voiceless, Glottal, Fricative),
+            'j' => Features::new(Voiced, Postalveolar, Affricate),
+            'l' => Features::new(Voiced, Alveolar, Approximant),
+            'r' => Features::new(Voiced, Postalveolar, Approximant),
+            'm' => Features::new(Voiced, Bilabial, Nasal),
+            'n' => Features::new(Voiced, Alveolar, Nasal),
+            'p' => Features::new(Voiceless, Bilabial, Stop),
+            's' => Features::new(Voiceless, Alveolar, Fricative),
+            't' => Features::new(Voiceless, Alveolar, Stop),
+            'v' => Features::new(Voiced, Labiodental, Fricative),
+            'w' => Features::new(Voiced, Bilabial, Approximant),
+            'z' => Features::new(Voiced, Alveolar, Fricative),
+            _ => return None,
+        };
+
+        Some(features)
+    }
+}
+
+impl SoundKind {
+    /// Returns articulatory features of the sound kind,
+    /// none if they depend on the letter, e.g. p, t or c of `Ptk`
+    pub fn features(&self) -> Option<Features> {
+        use Manner::*;
+        use Place::*;
+        use Voicing::*;
+
+        let features = match self {
+            SoundKind::Th => Features::new(Voiceless, Dental, Fricative),
+            SoundKind::W => Features::new(Voiced, Bilabial, Approximant),
+            SoundKind::V => Features::new(Voiced, Labiodental, Fricative),
+            SoundKind::B => Features::new(Voiced, Bilabial, Stop),
+            SoundKind::Ng => Features::new(Voiced, Velar, Nasal),
+            SoundKind::Ch => Features::new(Voiceless, Postalveolar, Affricate),
+            SoundKind::Dj => Features::new(Voiced, Postalveolar, Affricate),
+            SoundKind::Flap => Features::new(Voiced, Alveolar, Manner::Flap),
+            SoundKind::GlottalStop => Features::new(Voiceless, Glottal, Stop),
+            SoundKind::Ich => Features::new(Voiceless, Palatal, Fricative),
+            SoundKind::Ach => Features::new(Voiceless, Velar, Fricative),
+            SoundKind::R => Features::new(Voiced, Postalveolar, Approximant),
+            SoundKind::L => Features::new(Voiced, Alveolar, Approximant),
+            SoundKind::Ptk | SoundKind::Devoiced | SoundKind::Undefined => return None,
+        };
+
+        Some(features)
+    }
+}
+
 /// Struct of the sound
 #[derive(Debug)]
 pub struct Sound {
@@ -20,6 +177,8 @@ pub struct Sound {
     id: Uuid,
     kind: SoundKind,
     text: String,
+    rule: Option<String>,
+    is_final: bool,
 }
 
 impl PartialEq for Sound {
@@ -35,11 +194,129 @@ impl Sound {
             id: Uuid::new_v4(),
             kind,
             text,
+            rule: None,
+            is_final: false,
+        }
+    }
+
+    /// Creates new Sound with the rule that produced it, e.g. "word-final p, t or c → Ptk"
+    pub fn with_rule(kind: SoundKind, text: String, rule: String) -> Self {
+        Self {
+            rule: Some(rule),
+            ..Self::new(kind, text)
         }
     }
 
+    /// Returns kind of the sound
+    pub fn kind(&self) -> &SoundKind {
+        &self.kind
+    }
+
     /// Returns text of the sound
     pub fn text(&self) -> &String {
         &self.text
     }
+
+    /// Returns the rule that produced the sound, if any
+    pub fn rule(&self) -> Option<&str> {
+        self.rule.as_deref()
+    }
+
+    /// Returns true if the sound is a consonant at the end of the word, e.g. "g" and "s" in "dogs"
+    pub fn is_final(&self) -> bool {
+        self.is_final
+    }
+
+    /// Marks the sound as a consonant at the end of the word
+    pub(crate) fn set_final(&mut self) {
+        self.is_final = true;
+    }
+
+    /// Returns true if the sound is a consonant by its features
+    pub(crate) fn is_consonant(&self) -> bool {
+        self.features()
+            .is_some_and(|features| features.manner != Manner::Vowel)
+    }
+
+    /// Returns articulatory features of the sound by its kind or by its first base letter, e.g. "e" of "é"
+    pub fn features(&self) -> Option<Features> {
+        if let Some(features) = self.kind.features() {
+            return Some(features);
+        }
+
+        let features = Features::of_letter(self.text.nfd().next()?)?;
+
+        match self.kind {
+            SoundKind::Ptk | SoundKind::Devoiced => Some(Features {
+                voicing: Voicing::Voiceless,
+                ..features
+            }),
+            _ => Some(features),
+        }
+    }
+}
+
+#[cfg(test)]
+mod sound_kind_from_str {
+    use super::{Error, SoundKind};
+
+    #[test]
+    fn it_should_parse_sound_kind() {
+        assert_eq!("Ptk".parse::<SoundKind>(), Ok(SoundKind::Ptk));
+        assert_eq!("Dj".parse::<SoundKind>(), Ok(SoundKind::Dj));
+        assert_eq!(
+            "GlottalStop".parse::<SoundKind>(),
+            Ok(SoundKind::GlottalStop)
+        );
+    }
+
+    #[test]
+    fn it_should_not_parse_unknown_sound_kind() {
+        assert_eq!(
+            "ptk".parse::<SoundKind>(),
+            Err(Error::UnknownSoundKind(String::from("ptk")))
+        );
+    }
+}
+
+#[cfg(test)]
+mod features {
+    use super::{Features, Manner, Place, Sound, SoundKind, Voicing};
+
+    #[test]
+    fn it_should_return_features_of_kind() {
+        assert_eq!(
+            SoundKind::Th.features(),
+            Some(Features {
+                voicing: Voicing::Voiceless,
+                place: Place::Dental,
+                manner: Manner::Fricative
+            })
+        );
+        assert_eq!(SoundKind::Ptk.features(), None);
+    }
+
+    #[test]
+    fn it_should_return_features_of_letter() {
+        let sound = Sound::new(SoundKind::Devoiced, String::from("d"));
+
+        assert_eq!(
+            sound.features(),
+            Some(Features {
+                voicing: Voicing::Voiceless,
+                place: Place::Alveolar,
+                manner: Manner::Stop
+            })
+        );
+        assert_eq!(
+            Sound::new(SoundKind::Undefined, String::from("E"))
+                .features()
+                .map(|f| f.manner),
+            Some(Manner::Vowel)
+        );
+        assert_eq!(
+            Sound::new(SoundKind::Undefined, String::from("'")).features(),
+            None
+        );
+    }
 }
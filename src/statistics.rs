@@ -0,0 +1,304 @@
+use crate::options::Options;
+use crate::parser::parse_words_with;
+use crate::sound::SoundKind;
+use std::cmp::Reverse;
+use std::fmt;
+
+/// Number of the most frequent words of the sound shown by `Display`
+const DISPLAYED_WORDS: usize = 3;
+
+/// Position of the sound in the word
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Position {
+    /// The first sound of the word, e.g. "p" in "put"
+    Initial,
+    /// Sounds between the first and the last ones, e.g. "th" in "other"
+    Medial,
+    /// The last sound of the word, e.g. "t" in "put"
+    Final,
+}
+
+impl Position {
+    fn of(index: usize, len: usize) -> Self {
+        match index {
+            0 => Position::Initial,
+            index if index + 1 == len => Position::Final,
+            _ => Position::Medial,
+        }
+    }
+}
+
+/// Statistics of the sounds of one kind
+#[derive(Debug, Clone, PartialEq)]
+pub struct KindStats {
+    kind: SoundKind,
+    count: usize,
+    percentage: f64,
+    density: f64,
+    positions: [usize; 3],
+    words: Vec<(String, usize)>,
+}
+
+impl KindStats {
+    /// Returns kind of the sounds
+    pub fn kind(&self) -> SoundKind {
+        self.kind
+    }
+
+    /// Returns number of the sounds
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns percentage of the sounds among all sounds of the words
+    pub fn percentage(&self) -> f64 {
+        self.percentage
+    }
+
+    /// Returns number of the sounds per word
+    pub fn density(&self) -> f64 {
+        self.density
+    }
+
+    /// Returns number of the sounds in the position of the word
+    pub fn position(&self, position: Position) -> usize {
+        self.positions[position as usize]
+    }
+
+    /// Returns lowercase words with the sound from the most frequent one, with their counts
+    pub fn words(&self) -> &[(String, usize)] {
+        &self.words
+    }
+}
+
+/// Frequency statistics of the sounds of the text
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SoundStats {
+    sounds: usize,
+    words: usize,
+    kinds: Vec<KindStats>,
+}
+
+impl SoundStats {
+    /// Returns number of all sounds of the words, undefined ones too
+    pub fn sounds(&self) -> usize {
+        self.sounds
+    }
+
+    /// Returns number of the words
+    pub fn words(&self) -> usize {
+        self.words
+    }
+
+    /// Returns number of the defined sounds per word
+    pub fn density(&self) -> f64 {
+        self.kinds.iter().map(KindStats::density).sum()
+    }
+
+    /// Returns statistics per kind from the most frequent one, undefined sounds are skipped
+    pub fn kinds(&self) -> &[KindStats] {
+        &self.kinds
+    }
+
+    /// Returns statistics of the kind if the text has such sounds
+    pub fn kind(&self, kind: SoundKind) -> Option<&KindStats> {
+        self.kinds.iter().find(|stats| stats.kind == kind)
+    }
+}
+
+impl fmt::Display for SoundStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "Sounds: {}, words: {}, density: {:.2} per word",
+            self.sounds,
+            self.words,
+            self.density()
+        )?;
+
+        for stats in &self.kinds {
+            let words = stats
+                .words
+                .iter()
+                .take(DISPLAYED_WORDS)
+                .map(|(word, count)| format!("{} ({})", word, count))
+                .collect::<Vec<String>>()
+                .join(", ");
+
+            writeln!(
+                f,
+                "{:<14} {:>5} {:>7.2}% initial {:>4} medial {:>4} final {:>4}  {}",
+                format!("{:?}", stats.kind),
+                stats.count,
+                stats.percentage,
+                stats.position(Position::Initial),
+                stats.position(Position::Medial),
+                stats.position(Position::Final),
+                words
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Count sounds of the text per kind, per word and per position in the word
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::{stats, Position, SoundKind};
+///
+/// let stats = stats("Put the cat");
+/// let ptk = stats.kind(SoundKind::Ptk).unwrap();
+///
+/// assert_eq!(stats.sounds(), 8);
+/// assert_eq!(ptk.count(), 4);
+/// assert_eq!(ptk.percentage(), 50.0);
+/// assert_eq!(ptk.position(Position::Final), 2);
+/// assert_eq!(ptk.words()[0], (String::from("put"), 2));
+/// ```
+pub fn stats<T: AsRef<str>>(text: T) -> SoundStats {
+    stats_with(text, &Options::default())
+}
+
+/// Count sounds of the text with the options
+pub fn stats_with<T: AsRef<str>>(text: T, options: &Options) -> SoundStats {
+    let mut stats = SoundStats::default();
+
+    for word in parse_words_with(text, options) {
+        if word.is_separator() {
+            continue;
+        }
+
+        stats.words += 1;
+        stats.sounds += word.sounds().len();
+
+        for (index, sound) in word.sounds().iter().enumerate() {
+            if sound.kind() == &SoundKind::Undefined {
+                continue;
+            }
+
+            let kind_stats = match stats.kinds.iter().position(|s| &s.kind == sound.kind()) {
+                Some(position) => &mut stats.kinds[position],
+                None => {
+                    stats.kinds.push(KindStats {
+                        kind: *sound.kind(),
+                        count: 0,
+                        percentage: 0.0,
+                        density: 0.0,
+                        positions: [0; 3],
+                        words: vec![],
+                    });
+
+                    stats.kinds.last_mut().unwrap()
+                }
+            };
+
+            kind_stats.count += 1;
+            kind_stats.positions[Position::of(index, word.sounds().len()) as usize] += 1;
+
+            match kind_stats
+                .words
+                .iter_mut()
+                .find(|(text, _)| text == word.normalized())
+            {
+                Some((_, count)) => *count += 1,
+                None => kind_stats.words.push((word.normalized().to_string(), 1)),
+            }
+        }
+    }
+
+    for kind_stats in &mut stats.kinds {
+        kind_stats.percentage = kind_stats.count as f64 * 100.0 / stats.sounds as f64;
+        kind_stats.density = kind_stats.count as f64 / stats.words as f64;
+        kind_stats.words.sort_by_key(|(_, count)| Reverse(*count));
+    }
+
+    stats
+        .kinds
+        .sort_by_key(|kind_stats| Reverse(kind_stats.count));
+
+    stats
+}
+
+#[cfg(test)]
+mod stats {
+    use super::{stats, stats_with, Options, Position, SoundKind};
+
+    #[test]
+    fn it_should_count_empty() {
+        let stats = stats("");
+
+        assert_eq!(stats.sounds(), 0);
+        assert_eq!(stats.words(), 0);
+        assert_eq!(stats.density(), 0.0);
+        assert!(stats.kinds().is_empty());
+    }
+
+    #[test]
+    fn it_should_count_kinds_from_the_most_frequent() {
+        let stats = stats("The cat, the thing!");
+
+        let kinds = stats
+            .kinds()
+            .iter()
+            .map(|stats| (stats.kind(), stats.count()))
+            .collect::<Vec<(SoundKind, usize)>>();
+
+        assert_eq!(
+            kinds,
+            vec![(SoundKind::Th, 3), (SoundKind::Ptk, 2), (SoundKind::Ng, 1)]
+        );
+        assert_eq!(stats.words(), 4);
+        assert_eq!(stats.sounds(), 10);
+        assert_eq!(stats.density(), 1.5);
+    }
+
+    #[test]
+    fn it_should_count_positions_and_words() {
+        let stats = stats("The other, the bath");
+        let th = stats.kind(SoundKind::Th).unwrap();
+
+        assert_eq!(th.position(Position::Initial), 2);
+        assert_eq!(th.position(Position::Medial), 1);
+        assert_eq!(th.position(Position::Final), 1);
+        assert_eq!(
+            th.words(),
+            &[
+                (String::from("the"), 2),
+                (String::from("other"), 1),
+                (String::from("bath"), 1)
+            ][..]
+        );
+        assert_eq!(th.density(), 1.0);
+    }
+
+    #[test]
+    fn it_should_count_with_options() {
+        let options = Options {
+            expand: true,
+            ..Options::default()
+        };
+
+        assert!(stats("3").kind(SoundKind::Th).is_none());
+        assert_eq!(
+            stats_with("3", &options)
+                .kind(SoundKind::Th)
+                .unwrap()
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn it_should_display_stats() {
+        let text = stats("Put the cat").to_string();
+
+        assert_eq!(
+            text.lines().next(),
+            Some("Sounds: 8, words: 3, density: 1.67 per word")
+        );
+        assert!(text.contains("put (2), cat (2)"));
+    }
+}
@@ -0,0 +1,207 @@
+use std::ops::Range;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Characters that UAX #29 keeps inside words, but English text uses as separators
+pub(crate) const MID_LETTER_SEPARATORS: [char; 1] = [':'];
+
+/// Kinds of the tokens
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Word,
+    Separator,
+}
+
+/// Token of the text: a word or a separator between words
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token<'a> {
+    kind: TokenKind,
+    text: &'a str,
+    span: Range<usize>,
+}
+
+impl<'a> Token<'a> {
+    fn new(kind: TokenKind, text: &'a str, start: usize) -> Self {
+        Self {
+            kind,
+            text,
+            span: start..start + text.len(),
+        }
+    }
+
+    /// Returns kind of the token
+    pub fn kind(&self) -> TokenKind {
+        self.kind
+    }
+
+    /// Returns text of the token
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// Returns byte span of the token in the source text
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Returns true if the token is a word
+    pub fn is_word(&self) -> bool {
+        self.kind == TokenKind::Word
+    }
+}
+
+/// Split text to words and separators using Unicode word segmentation ([UAX #29](https://unicode.org/reports/tr29/))
+///
+/// ## Example
+///
+/// ```rust
+/// use text_to_sounds::{tokenize, TokenKind};
+///
+/// let tokens = tokenize("Don't stop,\tplease");
+///
+/// let words = tokens
+///     .iter()
+///     .filter(|token| token.kind() == TokenKind::Word)
+///     .map(|token| token.text())
+///     .collect::<Vec<&str>>();
+///
+/// assert_eq!(words, vec!["Don't", "stop", "please"]);
+/// assert_eq!(tokens[3].span(), 10..11);
+/// ```
+pub fn tokenize(text: &str) -> Vec<Token<'_>> {
+    tokenize_with(text, &MID_LETTER_SEPARATORS)
+}
+
+/// Split text to words and separators, the separators characters also split words
+pub(crate) fn tokenize_with<'a>(text: &'a str, separators: &[char]) -> Vec<Token<'a>> {
+    let mut tokens = vec![];
+
+    for (start, segment) in text.split_word_bound_indices() {
+        match segment.chars().any(char::is_alphanumeric) {
+            true => push_word(segment, start, separators, &mut tokens),
+            false => tokens.push(Token::new(TokenKind::Separator, segment, start)),
+        }
+    }
+
+    tokens
+}
+
+/// Pushes the word segment, splitting it by the separators
+fn push_word<'a>(segment: &'a str, start: usize, separators: &[char], tokens: &mut Vec<Token<'a>>) {
+    let mut word_start = 0;
+
+    for (index, character) in segment.char_indices() {
+        if separators.contains(&character) {
+            tokens.push(Token::new(
+                TokenKind::Word,
+                &segment[word_start..index],
+                start + word_start,
+            ));
+            tokens.push(Token::new(
+                TokenKind::Separator,
+                &segment[index..index + character.len_utf8()],
+                start + index,
+            ));
+
+            word_start = index + character.len_utf8();
+        }
+    }
+
+    tokens.push(Token::new(
+        TokenKind::Word,
+        &segment[word_start..],
+        start + word_start,
+    ));
+}
+
+#[cfg(test)]
+mod tokenize {
+    use super::{tokenize, tokenize_with, TokenKind};
+
+    fn words(text: &str) -> Vec<&str> {
+        tokenize(text)
+            .into_iter()
+            .filter(|token| token.is_word())
+            .map(|token| token.text())
+            .collect()
+    }
+
+    #[test]
+    fn it_should_tokenize_empty() {
+        assert!(tokenize("").is_empty());
+    }
+
+    #[test]
+    fn it_should_tokenize_spaces() {
+        let tokens = tokenize("put  together");
+
+        assert_eq!(tokens.len(), 3);
+        assert_eq!(tokens[0].kind(), TokenKind::Word);
+        assert_eq!(tokens[1].kind(), TokenKind::Separator);
+        assert_eq!(tokens[1].text(), "  ");
+        assert_eq!(tokens[2].span(), 5..13);
+    }
+
+    #[test]
+    fn it_should_tokenize_punctuation_and_non_breakable_space() {
+        assert_eq!(
+            words("what!the such-exp:the\u{a0}going?"),
+            vec!["what", "the", "such", "exp", "the", "going"]
+        );
+    }
+
+    #[test]
+    fn it_should_tokenize_newlines_and_tabs() {
+        assert_eq!(
+            words("one\ntwo\r\nthree\tfour"),
+            vec!["one", "two", "three", "four"]
+        );
+    }
+
+    #[test]
+    fn it_should_tokenize_quotes_and_parentheses() {
+        assert_eq!(
+            words("«quoted» \"text\" (in brackets)"),
+            vec!["quoted", "text", "in", "brackets"]
+        );
+    }
+
+    #[test]
+    fn it_should_tokenize_em_dash() {
+        assert_eq!(words("this—that"), vec!["this", "that"]);
+    }
+
+    #[test]
+    fn it_should_keep_apostrophes_inside_words() {
+        assert_eq!(words("don't 'quote'"), vec!["don't", "quote"]);
+    }
+
+    #[test]
+    fn it_should_tokenize_digits() {
+        assert_eq!(words("at 10 or 3.14"), vec!["at", "10", "or", "3.14"]);
+    }
+
+    #[test]
+    fn it_should_tokenize_cjk() {
+        assert_eq!(words("中文 text"), vec!["中", "文", "text"]);
+    }
+
+    #[test]
+    fn it_should_tokenize_with_separators() {
+        let words = tokenize_with("Lehrer:innen", &[])
+            .into_iter()
+            .filter(|token| token.is_word())
+            .map(|token| token.text())
+            .collect::<Vec<&str>>();
+
+        assert_eq!(words, vec!["Lehrer:innen"]);
+    }
+
+    #[test]
+    fn it_should_keep_byte_spans() {
+        let text = "«put» it";
+
+        for token in tokenize(text) {
+            assert_eq!(&text[token.span()], token.text());
+        }
+    }
+}
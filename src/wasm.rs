@@ -1,4 +1,9 @@
-use crate::highlight;
+use crate::grader::readability;
+use crate::lexicon::Lexicon;
+use crate::options::Options;
+use crate::profile::Profile;
+use crate::statistics::{stats, Position};
+use crate::{highlight, highlight_with};
 use wasm_bindgen::prelude::*;
 
 /// Highlight sounds in the text with html tags (wasm)
@@ -14,3 +19,134 @@ use wasm_bindgen::prelude::*;
 pub fn highlight_wasm(text: &str) -> String {
     highlight(text)
 }
+
+/// Highlight sounds in the text with html tags and the lexicon in the text format (wasm)
+///
+/// ## Example
+///
+/// ```js
+/// import {highlight_with_lexicon_wasm} from "text-to-sounds";
+///
+/// console.log(highlight_with_lexicon_wasm("Thames", "thames = th/Ptk a m e s") === "<span class='Ptk'>Th</span>ames"); // true
+/// ```
+#[wasm_bindgen]
+pub fn highlight_with_lexicon_wasm(text: &str, lexicon: &str) -> Result<String, JsValue> {
+    let lexicon = lexicon
+        .parse::<Lexicon>()
+        .map_err(|error| JsValue::from_str(&error.to_string()))?;
+
+    let options = Options {
+        lexicon,
+        ..Options::default()
+    };
+
+    Ok(highlight_with(text, &options))
+}
+
+/// Highlight sounds in the text with html tags for the learners of the bundled profile (wasm),
+/// e.g. "ru", "es", "ja" or "zh"
+///
+/// ## Example
+///
+/// ```js
+/// import {highlight_with_profile_wasm} from "text-to-sounds";
+///
+/// console.log(highlight_with_profile_wasm("Very bad", "es") === "<span class='V'>V</span>ery <span class='B'>b</span>ad"); // true
+/// ```
+#[wasm_bindgen]
+pub fn highlight_with_profile_wasm(text: &str, profile: &str) -> Result<String, JsValue> {
+    let profile =
+        Profile::bundled(profile).map_err(|error| JsValue::from_str(&error.to_string()))?;
+
+    let options = Options {
+        profile: Some(profile),
+        ..Options::default()
+    };
+
+    Ok(highlight_with(text, &options))
+}
+
+/// Frequency statistics of the sounds of the text as html table (wasm)
+///
+/// ## Example
+///
+/// ```js
+/// import {stats_wasm} from "text-to-sounds";
+///
+/// document.getElementById('stats').innerHTML = stats_wasm("Put the cat");
+/// ```
+#[wasm_bindgen]
+pub fn stats_wasm(text: &str) -> String {
+    let stats = stats(text);
+
+    let mut html = format!(
+        "<table><caption>Sounds: {}, words: {}, density: {:.2} per word</caption>",
+        stats.sounds(),
+        stats.words(),
+        stats.density()
+    );
+
+    html.push_str("<tr><th>Sound</th><th>Count</th><th>%</th><th>Initial</th><th>Medial</th><th>Final</th><th>Words</th></tr>");
+
+    for kind in stats.kinds() {
+        let words = kind
+            .words()
+            .iter()
+            .take(3)
+            .map(|(word, count)| format!("{} ({})", word, count))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        html.push_str(&format!(
+            "<tr><td class='{:?}'>{:?}</td><td>{}</td><td>{:.2}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+            kind.kind(),
+            kind.kind(),
+            kind.count(),
+            kind.percentage(),
+            kind.position(Position::Initial),
+            kind.position(Position::Medial),
+            kind.position(Position::Final),
+            words
+        ));
+    }
+
+    html.push_str("</table>");
+
+    html
+}
+
+/// Readability metrics of the text as html table (wasm)
+///
+/// ## Example
+///
+/// ```js
+/// import {readability_wasm} from "text-to-sounds";
+///
+/// document.getElementById('readability').innerHTML = readability_wasm("The cat sat on the mat.");
+/// ```
+#[wasm_bindgen]
+pub fn readability_wasm(text: &str) -> String {
+    let readability = readability(text);
+
+    let mut html = format!(
+        "<table><caption>Words: {}, sentences: {}, syllables: {}</caption>",
+        readability.words(),
+        readability.sentences(),
+        readability.syllables()
+    );
+
+    let metrics = [
+        ("Flesch Reading Ease", readability.flesch_reading_ease()),
+        ("Flesch-Kincaid Grade", readability.flesch_kincaid_grade()),
+        ("SMOG", readability.smog()),
+        ("Coleman-Liau", readability.coleman_liau()),
+    ];
+
+    for (name, value) in metrics {
+        html.push_str(&format!("<tr><th>{}</th><td>{:.2}</td></tr>", name, value));
+    }
+
+    html.push_str("</table>");
+
+    html
+}
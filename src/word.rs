@@ -0,0 +1,135 @@
+use crate::homograph::Variant;
+use crate::sound::Sound;
+use crate::tokenizer::TokenKind;
+use std::ops::Range;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Struct of the word (or the separator between words) with its sounds
+#[derive(Debug, PartialEq)]
+pub struct Word {
+    kind: TokenKind,
+    text: String,
+    span: Range<usize>,
+    normalized: String,
+    sounds: Vec<Sound>,
+    is_expanded: bool,
+    variant: Option<Variant>,
+}
+
+impl Word {
+    /// Creates new Word
+    pub(crate) fn new(kind: TokenKind, text: &str, span: Range<usize>, sounds: Vec<Sound>) -> Self {
+        Self {
+            kind,
+            text: text.to_string(),
+            span,
+            normalized: text.nfc().collect::<String>().to_lowercase(),
+            sounds,
+            is_expanded: false,
+            variant: None,
+        }
+    }
+
+    /// Creates new Word from the source text spoken as other words, e.g. "3rd" spoken as "third"
+    pub(crate) fn expanded(
+        text: &str,
+        span: Range<usize>,
+        spoken: &str,
+        sounds: Vec<Sound>,
+    ) -> Self {
+        Self {
+            kind: TokenKind::Word,
+            text: text.to_string(),
+            span,
+            normalized: spoken.nfc().collect::<String>().to_lowercase(),
+            sounds,
+            is_expanded: true,
+            variant: None,
+        }
+    }
+
+    /// Returns kind of the word
+    pub fn kind(&self) -> TokenKind {
+        self.kind
+    }
+
+    /// Returns true if the word is a separator between words
+    pub fn is_separator(&self) -> bool {
+        self.kind == TokenKind::Separator
+    }
+
+    /// Returns source text of the word
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns byte span of the word in the source text
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Returns true if the word is spoken as other words, then sounds are of the spoken words
+    pub fn is_expanded(&self) -> bool {
+        self.is_expanded
+    }
+
+    /// Returns lowercase NFC normalized text of the word (spoken words if expanded), useful for lookups
+    pub fn normalized(&self) -> &str {
+        &self.normalized
+    }
+
+    /// Returns pronunciation variant of the homograph chosen by the context, e.g. "read" as "/rɛd/"
+    pub fn variant(&self) -> Option<&Variant> {
+        self.variant.as_ref()
+    }
+
+    /// Sets pronunciation variant of the homograph
+    pub(crate) fn set_variant(&mut self, variant: Variant) {
+        self.variant = Some(variant);
+    }
+
+    /// Returns sounds of the word
+    pub fn sounds(&self) -> &[Sound] {
+        &self.sounds
+    }
+
+    /// Returns byte spans of the sounds in the source text, mapped by graphemes, so NFC normalized
+    /// sounds of decomposed text keep their source offsets. Sounds of the expanded word
+    /// (or of a word spoken not as written) span the whole word.
+    pub(crate) fn sound_spans(&self) -> Vec<Range<usize>> {
+        let spoken = self
+            .sounds
+            .iter()
+            .map(|sound| sound.text().as_str())
+            .collect::<String>();
+
+        if self.is_expanded || spoken.nfc().ne(self.text.nfc()) {
+            return vec![self.span(); self.sounds.len()];
+        }
+
+        let offsets = self
+            .text
+            .grapheme_indices(true)
+            .map(|(offset, _)| self.span.start + offset)
+            .chain([self.span.end])
+            .collect::<Vec<usize>>();
+        let mut index = 0;
+
+        self.sounds
+            .iter()
+            .map(|sound| {
+                let start = offsets[index];
+
+                index = (index + sound.text().graphemes(true).count()).min(offsets.len() - 1);
+
+                start..offsets[index]
+            })
+            .collect()
+    }
+
+    /// Returns sounds of the word consuming it
+    pub fn into_sounds(self) -> Vec<Sound> {
+        self.sounds
+    }
+}
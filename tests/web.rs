@@ -5,7 +5,10 @@
 extern crate wasm_bindgen_test;
 use wasm_bindgen_test::*;
 extern crate text_to_sounds;
-use text_to_sounds::highlight_wasm;
+use text_to_sounds::{
+    highlight_wasm, highlight_with_lexicon_wasm, highlight_with_profile_wasm, readability_wasm,
+    stats_wasm,
+};
 
 wasm_bindgen_test_configure!(run_in_browser);
 
@@ -18,3 +21,31 @@ fn pass() {
 fn it_should_highlight_ptk() {
     assert_eq!(highlight_wasm("Put a cat"), "<span class='Ptk'>P</span>u<span class='Ptk'>t</span> a <span class='Ptk'>c</span>a<span class='Ptk'>t</span>".to_string());
 }
+
+#[wasm_bindgen_test]
+fn it_should_highlight_with_lexicon() {
+    assert_eq!(
+        highlight_with_lexicon_wasm("Thames", "thames = th/Ptk a m e s"),
+        Ok("<span class='Ptk'>Th</span>ames".to_string())
+    );
+}
+
+#[wasm_bindgen_test]
+fn it_should_highlight_with_profile() {
+    assert_eq!(
+        highlight_with_profile_wasm("Very bad", "es"),
+        Ok("<span class='V'>V</span>ery <span class='B'>b</span>ad".to_string())
+    );
+}
+
+#[wasm_bindgen_test]
+fn it_should_show_stats() {
+    assert!(stats_wasm("Put the cat")
+        .starts_with("<table><caption>Sounds: 8, words: 3, density: 1.67 per word</caption>"));
+}
+
+#[wasm_bindgen_test]
+fn it_should_show_readability() {
+    assert!(readability_wasm("The cat sat on the mat.")
+        .starts_with("<table><caption>Words: 6, sentences: 1, syllables: 6</caption>"));
+}
@@ -1,9 +1,10 @@
-import { highlight_wasm } from 'text-to-sounds';
+import { highlight_wasm, stats_wasm } from 'text-to-sounds';
 
 const MAX_TEXT_LENGTH = 10000;
 
 const contenteditableEl = document.getElementById('contenteditable');
 const maxLengthTextEl = document.getElementById('max-length-text');
+const statsEl = document.getElementById('stats');
 
 const contenteditableFocus = () => {
     contenteditableEl.focus();
@@ -31,6 +32,7 @@ const contenteditableHighlight = () => {
     const isMaxTextLength = textContent.length >= MAX_TEXT_LENGTH;
 
     contenteditableEl.innerHTML = highlight_wasm(textContent.slice(0, MAX_TEXT_LENGTH));
+    statsEl.innerHTML = stats_wasm(textContent.slice(0, MAX_TEXT_LENGTH));
     document.execCommand('selectAll', false, null);
     document.getSelection().collapseToEnd();
 